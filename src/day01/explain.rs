@@ -0,0 +1,197 @@
+use crate::Part;
+
+use clap::ValueEnum;
+
+use super::{
+    scanner::{DigitKind, DigitScanner, Match},
+    DigitVocabulary,
+};
+
+/// how an explain report is written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExplainFormat {
+    /// one aligned row per line, easy to `diff` between parts
+    Table,
+    /// a single JSON object, with one entry per line
+    Json,
+}
+
+/// the digits picked out of a single line, by its 1-based line number.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct LineReport {
+    line: usize,
+    content: String,
+    first: Option<Match>,
+    last: Option<Match>,
+}
+
+impl LineReport {
+    /// `None` for lines without any digits.
+    fn value(&self) -> Option<u32> {
+        Some(self.first?.value * 10 + self.last?.value)
+    }
+
+    fn text_of(&self, m: &Match) -> &str {
+        &self.content[m.offset..m.offset + m.len]
+    }
+
+    /// `numeral`, or the word that was spelled out, ie `word(two)`
+    fn source_of(&self, m: &Match) -> String {
+        match m.kind {
+            DigitKind::Numeral => String::from("numeral"),
+            DigitKind::Word => format!("word({})", self.text_of(m)),
+        }
+    }
+}
+
+/// reports every line's first and last digit, without applying any malformed line policy.
+pub(crate) fn explain(input: Box<dyn Iterator<Item = String>>, part: Part, vocabulary: &DigitVocabulary) -> Vec<LineReport> {
+    let scanner = DigitScanner::for_part(part, vocabulary);
+
+    input.enumerate()
+        .map(|(idx, content)| LineReport {
+            line: idx + 1,
+            first: scanner.first(content.as_str()),
+            last: scanner.last(content.as_str()),
+            content,
+        })
+        .collect()
+}
+
+pub(crate) fn render(reports: &[LineReport], part: Part, format: ExplainFormat) -> String {
+    match format {
+        ExplainFormat::Table => render_table(reports),
+        ExplainFormat::Json => render_json(reports, part),
+    }
+}
+
+fn total(reports: &[LineReport]) -> u32 {
+    reports.iter().filter_map(|r| r.value()).sum()
+}
+
+/// kept fixed (rather than fitted to the longest word) so that tables for
+/// different parts or vocabularies only differ on the rows that changed.
+const SOURCE_WIDTH: usize = 14;
+
+fn render_table(reports: &[LineReport]) -> String {
+    let digit_cols = |r: &LineReport, m: Option<Match>| match m {
+        Some(m) => format!("{:>5}  {:>4}  {:<w$}", m.value, m.offset, r.source_of(&m), w = SOURCE_WIDTH),
+        None => format!("{:>5}  {:>4}  {:<w$}", "-", "-", "-", w = SOURCE_WIDTH),
+    };
+
+    let mut rows = vec![format!(
+        "{:>4}  {:>5}  {:>4}  {:<w$}  {:>5}  {:>4}  {:<w$}  {:>5}  {}",
+        "line", "first", "at", "from", "last", "at", "from", "value", "content",
+        w = SOURCE_WIDTH,
+    )];
+
+    rows.extend(reports.iter().map(|r| format!(
+        "{:>4}  {}  {}  {:>5}  {}",
+        r.line,
+        digit_cols(r, r.first),
+        digit_cols(r, r.last),
+        r.value().map(|v| v.to_string()).unwrap_or(String::from("-")),
+        r.content,
+    )));
+
+    rows.push(format!("total: {}", total(reports)));
+    rows.join("\n")
+}
+
+fn render_json(reports: &[LineReport], part: Part) -> String {
+    let digit = |r: &LineReport, m: Option<Match>| match m {
+        Some(m) => format!(
+            r#"{{"digit":{},"offset":{},"source":"{}","text":{}}}"#,
+            m.value,
+            m.offset,
+            match m.kind {
+                DigitKind::Numeral => "numeral",
+                DigitKind::Word => "word",
+            },
+            json_string(r.text_of(&m)),
+        ),
+        None => String::from("null"),
+    };
+
+    let lines: Vec<String> = reports.iter()
+        .map(|r| format!(
+            r#"{{"line":{},"content":{},"first":{},"last":{},"value":{}}}"#,
+            r.line,
+            json_string(r.content.as_str()),
+            digit(r, r.first),
+            digit(r, r.last),
+            r.value().map(|v| v.to_string()).unwrap_or(String::from("null")),
+        ))
+        .collect();
+
+    format!(
+        "{{\"part\":{},\"total\":{},\"lines\":[\n{}\n]}}",
+        json_string(format!("{:?}", part).as_str()),
+        total(reports),
+        lines.join(",\n"),
+    )
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(format!("\\u{:04x}", c as u32).as_str()),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+
+#[cfg(test)]
+fn example_reports(part: Part) -> Vec<LineReport> {
+    let lines = ["two1nine", "7pqrstsixteen", "no digits"].into_iter()
+        .map(String::from);
+    explain(Box::new(lines), part, &DigitVocabulary::english())
+}
+
+#[test]
+fn test_explain() {
+    let reports = example_reports(Part::Part2);
+
+    let first = reports[0].first.expect("has a first digit");
+    assert_eq!((first.value, first.offset, first.kind), (2, 0, DigitKind::Word));
+    assert_eq!(reports[0].value(), Some(29));
+    assert_eq!(reports[1].value(), Some(76));
+    assert_eq!(reports[2].value(), None);
+
+    // part 1 only sees the numerals in the same lines
+    let reports = example_reports(Part::Part1);
+    assert_eq!(reports[0].value(), Some(11));
+    assert_eq!(reports[1].value(), Some(77));
+}
+
+#[test]
+fn test_render_table() {
+    let output = render(&example_reports(Part::Part2), Part::Part2, ExplainFormat::Table);
+    assert_eq!(output, r"line  first    at  from             last    at  from            value  content
+   1      2     0  word(two)           9     4  word(nine)         29  two1nine
+   2      7     0  numeral             6     6  word(six)          76  7pqrstsixteen
+   3      -     -  -                   -     -  -                   -  no digits
+total: 105");
+}
+
+#[test]
+fn test_render_json() {
+    let output = render(&example_reports(Part::Part1), Part::Part1, ExplainFormat::Json);
+    assert_eq!(output, r#"{"part":"Part-1","total":88,"lines":[
+{"line":1,"content":"two1nine","first":{"digit":1,"offset":3,"source":"numeral","text":"1"},"last":{"digit":1,"offset":3,"source":"numeral","text":"1"},"value":11},
+{"line":2,"content":"7pqrstsixteen","first":{"digit":7,"offset":0,"source":"numeral","text":"7"},"last":{"digit":7,"offset":0,"source":"numeral","text":"7"},"value":77},
+{"line":3,"content":"no digits","first":null,"last":null,"value":null}
+]}"#);
+
+    assert_eq!(json_string("a \"b\"\\\n"), r#""a \"b\"\\\n""#);
+}
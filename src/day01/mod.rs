@@ -1,86 +1,97 @@
 use crate::Part;
 
-enum Digit {
-    One,
-    Two,
-    Three,
-    Four,
-    Five,
-    Six,
-    Seven,
-    Eight,
-    Nine,
+use std::fmt::Display;
+
+use clap::ValueEnum;
+
+mod explain;
+mod scanner;
+mod vocabulary;
+
+use scanner::DigitScanner;
+pub use explain::ExplainFormat;
+pub use vocabulary::{DigitVocabulary, VocabularyError};
+
+const NUMERALS: [(&str, u32); 9] = [
+    ("1", 1), ("2", 2), ("3", 3), ("4", 4), ("5", 5), ("6", 6), ("7", 7), ("8", 8), ("9", 9),
+];
+
+/// what to do with a line that has no digits in it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum MalformedLines {
+    /// stop at the first malformed line with an error
+    #[default]
+    Fail,
+    /// leave the line out, keeping count of how many were skipped
+    Skip,
+    /// count the line's calibration value as zero
+    Zero,
 }
 
-impl Digit {
-    fn parse_from(value: &str, only_digits: bool) -> Option<Self> {   
-        match value {
-            l if l.starts_with("1") => Some(Self::One),
-            l if l.starts_with("2") => Some(Self::Two),
-            l if l.starts_with("3") => Some(Self::Three),
-            l if l.starts_with("4") => Some(Self::Four),
-            l if l.starts_with("5") => Some(Self::Five),
-            l if l.starts_with("6") => Some(Self::Six),
-            l if l.starts_with("7") => Some(Self::Seven),
-            l if l.starts_with("8") => Some(Self::Eight),
-            l if l.starts_with("9") => Some(Self::Nine),
-            
-            l if !only_digits && l.starts_with("one") => Some(Self::One),
-            l if !only_digits && l.starts_with("two") => Some(Self::Two),
-            l if !only_digits && l.starts_with("three") => Some(Self::Three),
-            l if !only_digits && l.starts_with("four") => Some(Self::Four),
-            l if !only_digits && l.starts_with("five") => Some(Self::Five),
-            l if !only_digits && l.starts_with("six") => Some(Self::Six),
-            l if !only_digits && l.starts_with("seven") => Some(Self::Seven),
-            l if !only_digits && l.starts_with("eight") => Some(Self::Eight),
-            l if !only_digits && l.starts_with("nine") => Some(Self::Nine),
-
-            _ => None,
-        }
-    }
+#[derive(Debug, PartialEq, Eq)]
+pub struct Calibration {
+    pub total: u32,
+    /// lines left out under `MalformedLines::Skip`
+    pub skipped: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CalibrationError {
+    /// a line with no digits in it, by its 1-based line number
+    MissingDigit { line: usize, content: String },
 }
 
-impl Into<u32> for Digit {
-    fn into(self) -> u32 {
+impl Display for CalibrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Digit::One => 1,
-            Digit::Two => 2,
-            Digit::Three => 3,
-            Digit::Four => 4,
-            Digit::Five => 5,
-            Digit::Six => 6,
-            Digit::Seven => 7,
-            Digit::Eight => 8,
-            Digit::Nine => 9,
+            CalibrationError::MissingDigit { line, content } => {
+                write!(f, "line {line} has no digits: `{content}`")
+            }
         }
     }
 }
 
-pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+impl std::error::Error for CalibrationError {}
 
-    let total: u32 = input
-        .map(|line| {
-            let l = line.as_str();
-            let nums: Vec<u32> = (0..l.len()).filter_map(|idx| {
-                parse_digit(&l[idx..l.len()], part) 
-            }).collect();
+/// `vocabulary` supplies the spelled out digits `Part2` also looks for,
+/// and `malformed` decides what happens to lines without any digits.
+pub(crate) fn solve(
+    input: Box<dyn Iterator<Item = String>>,
+    part: Part,
+    vocabulary: &DigitVocabulary,
+    malformed: MalformedLines,
+) -> Result<Calibration, CalibrationError> {
 
-            (nums.first().expect("must have at least one digit per line") * 10) +
-            nums.last().expect("must have at least one digit per line")
-        }).sum();
+    let scanner = DigitScanner::for_part(part, vocabulary);
 
-    format!("{}", total)
-}
+    let mut calibration = Calibration { total: 0, skipped: 0 };
+    for (idx, line) in input.enumerate() {
+        let first = scanner.first(line.as_str());
+        let last = scanner.last(line.as_str());
 
-fn parse_digit(line: &str, part: Part) -> Option<u32> {
-    let d = match part {
-        Part::Part1 => Digit::parse_from(line, true),
-        Part::Part2 => Digit::parse_from(line, false),
-    };
-    match d {
-        Some(d) => Some(d.into()),
-        None => None,
+        match (first, last, malformed) {
+            (Some(first), Some(last), _) => calibration.total += first.value * 10 + last.value,
+            (_, _, MalformedLines::Zero) => (),
+            (_, _, MalformedLines::Skip) => calibration.skipped += 1,
+            (_, _, MalformedLines::Fail) => {
+                return Err(CalibrationError::MissingDigit { line: idx + 1, content: line });
+            }
+        }
     }
+
+    Ok(calibration)
+}
+
+/// a per-line breakdown of which digits were picked out and where, rather than just the total.
+/// lines without digits are reported as such, whatever the malformed line policy.
+pub(crate) fn explain(
+    input: Box<dyn Iterator<Item = String>>,
+    part: Part,
+    vocabulary: &DigitVocabulary,
+    format: ExplainFormat,
+) -> String {
+    let reports = explain::explain(input, part, vocabulary);
+    explain::render(&reports, part, format)
 }
 
 
@@ -93,10 +104,10 @@ a1b2c3d4e5f
 treb7uchet";
 
     let lines = EXAMPLE.split('\n')
-        .map(|item| String::from(item));
+        .map(String::from);
 
-    let output = solve(Box::new(lines), Part::Part1);
-    assert_eq!(output.as_str(), "142");
+    let output = solve(Box::new(lines), Part::Part1, &DigitVocabulary::english(), MalformedLines::Fail);
+    assert_eq!(output.map(|c| c.total), Ok(142));
 }
 
 #[test]
@@ -110,8 +121,105 @@ zoneight234
 7pqrstsixteen";
 
     let lines = EXAMPLE.split('\n')
-        .map(|item| String::from(item));
+        .map(String::from);
     
-    let output = solve(Box::new(lines), Part::Part2);
-    assert_eq!(output.as_str(), "281");
+    let output = solve(Box::new(lines), Part::Part2, &DigitVocabulary::english(), MalformedLines::Fail);
+    assert_eq!(output.map(|c| c.total), Ok(281));
+}
+
+#[test]
+fn test_input_vocabulary() {
+    const EXAMPLE: &str = r"zweiXneun
+achtzweidrei
+abceins2dreixyz
+fünf7fünf";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+
+    let output = solve(Box::new(lines), Part::Part2, &DigitVocabulary::german(), MalformedLines::Fail);
+    assert_eq!(output.map(|c| c.total), Ok(180));
+
+    // numerals are still recognised alongside the words
+    let lines = ["deuxneuf3", "7cinq"].into_iter()
+        .map(String::from);
+
+    let output = solve(Box::new(lines), Part::Part2, &DigitVocabulary::french(), MalformedLines::Fail);
+    assert_eq!(output.map(|c| c.total), Ok(98));
+}
+
+#[test]
+fn test_malformed_lines() {
+    const EXAMPLE: &str = "1abc2\nnothing here\ntreb7uchet\n";
+
+    let run = |policy| {
+        let lines = EXAMPLE.split('\n')
+            .map(String::from);
+        solve(Box::new(lines), Part::Part1, &DigitVocabulary::english(), policy)
+    };
+
+    assert_eq!(run(MalformedLines::Skip), Ok(Calibration { total: 89, skipped: 2 }));
+    assert_eq!(run(MalformedLines::Zero), Ok(Calibration { total: 89, skipped: 0 }));
+    assert_eq!(run(MalformedLines::Fail), Err(CalibrationError::MissingDigit {
+        line: 2,
+        content: String::from("nothing here"),
+    }));
+}
+
+#[cfg(test_output_bench)]
+#[cfg(test)]
+mod bench {
+    use std::time::{Duration, Instant};
+    use crate::Part;
+
+    /// the old approach, checking every spelling against every suffix of the line.
+    fn per_offset_solve(lines: &[String], part: Part) -> u32 {
+        let english = super::DigitVocabulary::english();
+        let spellings: Vec<(&str, u32)> = match part {
+            Part::Part1 => super::NUMERALS.to_vec(),
+            Part::Part2 => [super::NUMERALS.to_vec(), english.words()].concat(),
+        };
+
+        lines.iter()
+            .map(|l| {
+                let nums: Vec<u32> = (0..l.len()).filter_map(|idx| {
+                    spellings.iter()
+                        .find(|(s, _)| l[idx..].starts_with(s))
+                        .map(|(_, v)| *v)
+                }).collect();
+                nums[0] * 10 + nums[nums.len() - 1]
+            }).sum()
+    }
+
+    #[test]
+    fn scanner_vs_per_offset_bench() {
+        const NUM_RUNS: u32 = 100;
+
+        let input = std::fs::read_to_string("input/day-01").expect("day 1 input");
+        let lines: Vec<String> = input.lines()
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect();
+
+        for part in [Part::Part1, Part::Part2] {
+            let expected = per_offset_solve(&lines, part).to_string();
+            let output = super::solve(Box::new(lines.clone().into_iter()), part, &super::DigitVocabulary::english(), super::MalformedLines::Fail);
+            assert_eq!(output.map(|c| c.total.to_string()), Ok(expected));
+
+            let mut per_offset = Duration::default();
+            let mut scanner = Duration::default();
+            for _ in 0..NUM_RUNS {
+                let start = Instant::now();
+                per_offset_solve(&lines, part);
+                per_offset += Instant::now().duration_since(start);
+
+                let test_iter = Box::new(lines.clone().into_iter());
+                let start = Instant::now();
+                let _ = super::solve(test_iter, part, &super::DigitVocabulary::english(), super::MalformedLines::Fail);
+                scanner += Instant::now().duration_since(start);
+            }
+
+            println!("Day-01 {:?} per-offset: {:#?}  scanner: {:#?}", part, per_offset / NUM_RUNS, scanner / NUM_RUNS);
+        }
+    }
 }
@@ -0,0 +1,212 @@
+use std::collections::VecDeque;
+
+use crate::Part;
+
+use super::{DigitVocabulary, NUMERALS};
+
+/// whether a digit was written as a numeral or spelled out as a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DigitKind {
+    Numeral,
+    Word,
+}
+
+/// a digit found in a line, by its value and the bytes it spans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Match {
+    pub(crate) value: u32,
+    pub(crate) offset: usize,
+    pub(crate) len: usize,
+    pub(crate) kind: DigitKind,
+}
+
+/// finds the first and last digit in a line in a single pass each way.
+///
+/// the forward automaton is built over the digit spellings, the backward one
+/// over the same spellings reversed, so the last digit is just the first match
+/// found while walking the line from its end.
+#[derive(Debug)]
+pub(crate) struct DigitScanner {
+    patterns: Vec<(Vec<u8>, u32, DigitKind)>,
+    forward: Automaton,
+    backward: Automaton,
+    max_len: usize,
+}
+
+impl DigitScanner {
+    pub(crate) fn new(numerals: &[(&str, u32)], words: &[(&str, u32)]) -> DigitScanner {
+        let patterns: Vec<(Vec<u8>, u32, DigitKind)> = numerals.iter()
+            .map(|(s, v)| (s, v, DigitKind::Numeral))
+            .chain(words.iter().map(|(s, v)| (s, v, DigitKind::Word)))
+            .filter(|(s, _, _)| !s.is_empty())
+            .map(|(s, v, kind)| (s.as_bytes().to_vec(), *v, kind))
+            .collect();
+
+        let forward = Automaton::build(patterns.iter().map(|(p, _, _)| p.clone()));
+        let backward = Automaton::build(patterns.iter().map(|(p, _, _)| p.iter().rev().copied().collect()));
+        let max_len = patterns.iter().map(|(p, _, _)| p.len()).max().unwrap_or(0);
+
+        DigitScanner { patterns, forward, backward, max_len }
+    }
+
+    /// `Part1` only reads numerals, `Part2` adds the vocabulary's words.
+    pub(crate) fn for_part(part: Part, vocabulary: &DigitVocabulary) -> DigitScanner {
+        match part {
+            Part::Part1 => DigitScanner::new(&NUMERALS, &[]),
+            Part::Part2 => DigitScanner::new(&NUMERALS, &vocabulary.words()),
+        }
+    }
+
+    /// the digit starting earliest in `line`, taking the longest spelling starting there
+    /// so it agrees with `last` when one word is a prefix of another.
+    ///
+    /// matches come out of the automaton ordered by where they end, so scanning
+    /// carries on until no longer spelling could still start at or before the best so far.
+    pub(crate) fn first(&self, line: &str) -> Option<Match> {
+        let mut best: Option<Match> = None;
+
+        for (end, pattern) in self.forward.matches(line.bytes()) {
+            if best.is_some_and(|b| end >= b.offset + self.max_len) {
+                break;
+            }
+
+            let m = self.match_ending_at(pattern, end);
+            if best.is_none_or(|b| m.offset < b.offset || (m.offset == b.offset && m.len > b.len)) {
+                best = Some(m);
+            }
+        }
+
+        best
+    }
+
+    /// the digit starting latest in `line`.
+    pub(crate) fn last(&self, line: &str) -> Option<Match> {
+        let bytes = line.as_bytes();
+        let (rev_end, pattern) = self.backward.matches(bytes.iter().rev().copied()).next()?;
+
+        let offset = bytes.len() - 1 - rev_end;
+        let (spelling, value, kind) = &self.patterns[pattern];
+        Some(Match { value: *value, offset, len: spelling.len(), kind: *kind })
+    }
+
+    fn match_ending_at(&self, pattern: usize, end: usize) -> Match {
+        let (spelling, value, kind) = &self.patterns[pattern];
+        Match { value: *value, offset: end + 1 - spelling.len(), len: spelling.len(), kind: *kind }
+    }
+}
+
+const ROOT: usize = 0;
+
+/// an Aho–Corasick automaton with its failure links folded into a full
+/// byte transition table, so scanning is a single lookup per byte.
+#[derive(Debug)]
+struct Automaton {
+    transitions: Vec<[usize; 256]>,
+    /// the longest pattern ending at each state, if any
+    outputs: Vec<Option<(usize, usize)>>,
+}
+
+impl Automaton {
+    fn build(patterns: impl Iterator<Item = Vec<u8>>) -> Automaton {
+        let mut transitions = vec![[ROOT; 256]];
+        let mut outputs: Vec<Option<(usize, usize)>> = vec![None];
+        let mut has_edge = vec![[false; 256]];
+
+        // trie of every pattern
+        for (idx, pattern) in patterns.enumerate() {
+            let mut state = ROOT;
+            for b in pattern.iter() {
+                let b = *b as usize;
+                if !has_edge[state][b] {
+                    transitions.push([ROOT; 256]);
+                    outputs.push(None);
+                    has_edge.push([false; 256]);
+                    has_edge[state][b] = true;
+                    transitions[state][b] = transitions.len() - 1;
+                }
+                state = transitions[state][b];
+            }
+            if outputs[state].is_none_or(|(_, len)| pattern.len() > len) {
+                outputs[state] = Some((idx, pattern.len()));
+            }
+        }
+
+        // breadth first, point every missing edge at where its failure link would go
+        let mut fail = vec![ROOT; transitions.len()];
+        let mut queue: VecDeque<usize> = (0..256)
+            .filter(|b| has_edge[ROOT][*b])
+            .map(|b| transitions[ROOT][b])
+            .collect();
+
+        while let Some(state) = queue.pop_front() {
+            if outputs[state].is_none() {
+                outputs[state] = outputs[fail[state]];
+            }
+
+            for b in 0..256 {
+                if has_edge[state][b] {
+                    let child = transitions[state][b];
+                    fail[child] = transitions[fail[state]][b];
+                    queue.push_back(child);
+                } else {
+                    transitions[state][b] = transitions[fail[state]][b];
+                }
+            }
+        }
+
+        Automaton { transitions, outputs }
+    }
+
+    /// every `(end index, pattern)` match in `bytes`, ordered by where it ends.
+    /// where several patterns end at the same byte only the longest is reported.
+    fn matches<'a>(&'a self, bytes: impl Iterator<Item = u8> + 'a) -> impl Iterator<Item = (usize, usize)> + 'a {
+        bytes.enumerate()
+            .scan(ROOT, |state, (idx, b)| {
+                *state = self.transitions[*state][b as usize];
+                Some((idx, self.outputs[*state]))
+            })
+            .filter_map(|(idx, output)| output.map(|(pattern, _)| (idx, pattern)))
+    }
+}
+
+
+#[test]
+fn test_overlapping_words() {
+    let scanner = DigitScanner::for_part(Part::Part2, &DigitVocabulary::english());
+
+    let first = scanner.first("eightwo").expect("has a digit");
+    let last = scanner.last("eightwo").expect("has a digit");
+    assert_eq!((first.value, first.offset), (8, 0));
+    assert_eq!((last.value, last.offset), (2, 4));
+
+    let first = scanner.first("xtwone3four").expect("has a digit");
+    let last = scanner.last("zoneight").expect("has a digit");
+    assert_eq!((first.value, first.offset), (2, 1));
+    assert_eq!((last.value, last.offset), (8, 3));
+
+    let first = scanner.first("abcone2").expect("has a digit");
+    let last = scanner.last("abcone2").expect("has a digit");
+    assert_eq!((first.offset, first.len, first.kind), (3, 3, DigitKind::Word));
+    assert_eq!((last.offset, last.len, last.kind), (6, 1, DigitKind::Numeral));
+
+    assert_eq!(scanner.first("abcdef"), None);
+    assert_eq!(scanner.last(""), None);
+}
+
+#[test]
+fn test_earliest_start_wins() {
+    // `ab` ends first, but `xaby` starts earlier
+    let scanner = DigitScanner::new(&[], &[("xaby", 1), ("ab", 2)]);
+    assert_eq!(scanner.first("xaby").map(|m| m.value), Some(1));
+    assert_eq!(scanner.last("xaby").map(|m| m.value), Some(2));
+}
+
+#[test]
+fn test_longest_at_same_start() {
+    // `b` ends first, but `ba` starts at the same place and is the whole word
+    let scanner = DigitScanner::new(&[], &[("b", 3), ("ba", 6)]);
+    assert_eq!(scanner.first("ba").map(|m| m.value), Some(6));
+    assert_eq!(scanner.last("ba").map(|m| m.value), Some(6));
+    assert_eq!(scanner.first("xbab").map(|m| (m.value, m.offset)), Some((6, 1)));
+    assert_eq!(scanner.last("xbab").map(|m| (m.value, m.offset)), Some((3, 3)));
+}
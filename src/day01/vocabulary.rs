@@ -0,0 +1,173 @@
+use std::{
+    fmt::Display,
+    fs,
+    io,
+    path::Path,
+};
+
+use nom::{
+    character::complete::{self, multispace0, none_of, space1},
+    combinator::recognize,
+    error::VerboseError,
+    sequence::{separated_pair, terminated},
+    multi::many1,
+    IResult,
+};
+
+/// the spelled out words a calibration line may use for each digit.
+///
+/// numerals are always recognised on top of these, so a vocabulary only
+/// needs the words themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitVocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Default for DigitVocabulary {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl DigitVocabulary {
+    /// builds a vocabulary from a word to value table.  values must be single digits.
+    pub fn from_table<S: AsRef<str>>(table: impl IntoIterator<Item = (S, u32)>) -> Result<Self, VocabularyError> {
+        let words = table.into_iter()
+            .enumerate()
+            .map(|(index, (word, value))| {
+                let word = word.as_ref();
+                if word.is_empty() || value > 9 {
+                    return Err(VocabularyError::TableEntry { index, word: String::from(word), value });
+                }
+                Ok((String::from(word), value))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(DigitVocabulary { words })
+    }
+
+    pub fn english() -> Self {
+        Self::builtin_table(&["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"])
+    }
+
+    pub fn german() -> Self {
+        Self::builtin_table(&["eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"])
+    }
+
+    pub fn french() -> Self {
+        Self::builtin_table(&["un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf"])
+    }
+
+    pub fn spanish() -> Self {
+        Self::builtin_table(&["uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve"])
+    }
+
+    /// looks up a built-in vocabulary by its (english) language name.
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "english" => Some(Self::english()),
+            "german" => Some(Self::german()),
+            "french" => Some(Self::french()),
+            "spanish" => Some(Self::spanish()),
+            _ => None,
+        }
+    }
+
+    /// loads a vocabulary file, with one `word value` pair per line.
+    /// blank lines and lines starting with `#` are skipped.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, VocabularyError> {
+        let contents = fs::read_to_string(path).map_err(VocabularyError::Io)?;
+        Self::parse(contents.as_str())
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, VocabularyError> {
+        let table = contents.lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+            .map(|(idx, l)| match entry(l.trim()) {
+                Ok(("", (word, value))) if value <= 9 => Ok((word, value)),
+                _ => Err(VocabularyError::Entry { line: idx + 1, content: String::from(l) }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_table(table)
+    }
+
+    /// every `(word, value)` pair in the vocabulary.
+    pub(crate) fn words(&self) -> Vec<(&str, u32)> {
+        self.words.iter()
+            .map(|(w, v)| (w.as_str(), *v))
+            .collect()
+    }
+
+    fn builtin_table(words: &[&str; 9]) -> Self {
+        DigitVocabulary {
+            words: words.iter()
+                .zip(1..)
+                .map(|(w, v)| (String::from(*w), v))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum VocabularyError {
+    Io(io::Error),
+    /// a line in a vocabulary file that isn't a word followed by a single digit value
+    Entry { line: usize, content: String },
+    /// a `from_table` entry with an empty word or a value over 9, by its position in the table
+    TableEntry { index: usize, word: String, value: u32 },
+}
+
+impl Display for VocabularyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VocabularyError::Io(err) => write!(f, "unable to read vocabulary: {err}"),
+            VocabularyError::Entry { line, content } => {
+                write!(f, "invalid vocabulary entry on line {line}: `{content}` (expected `word digit`)")
+            }
+            VocabularyError::TableEntry { index, word, value } => {
+                write!(f, "invalid vocabulary table entry at index {index}: `{word}` = {value} (expected a word and a single digit)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VocabularyError {}
+
+// `zwei 2`
+fn entry(input: &str) -> IResult<&str, (&str, u32), VerboseError<&str>> {
+    terminated(
+        separated_pair(recognize(many1(none_of(" \t"))), space1, complete::u32),
+        multispace0,
+    )(input)
+}
+
+
+#[test]
+fn test_parse() {
+    let parsed = DigitVocabulary::parse(r"# obfuscated digits
+uno 1
+
+zwo 2
+").expect("valid vocabulary");
+    assert_eq!(parsed, DigitVocabulary::from_table([("uno", 1), ("zwo", 2)]).unwrap());
+
+    match DigitVocabulary::parse("uno 1\nzwo") {
+        Err(VocabularyError::Entry { line, content }) => assert_eq!((line, content.as_str()), (2, "zwo")),
+        other => panic!("expected an entry error, got {other:?}"),
+    }
+    assert!(DigitVocabulary::parse("dix 10").is_err());
+
+    match DigitVocabulary::from_table([("uno", 1), ("", 2)]) {
+        Err(VocabularyError::TableEntry { index, word, value }) => assert_eq!((index, word.as_str(), value), (1, "", 2)),
+        other => panic!("expected a table entry error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_builtin() {
+    assert_eq!(DigitVocabulary::builtin("German"), Some(DigitVocabulary::german()));
+    assert_eq!(DigitVocabulary::builtin("klingon"), None);
+    assert_eq!(DigitVocabulary::default(), DigitVocabulary::english());
+}
@@ -25,23 +25,22 @@ pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> Stri
             Some(game_obj)
         }).collect();
 
-    let total: u32;
-    match part {
+    let total: u32 = match part {
         Part::Part1 => {
-            total = games.iter().fold(0u32, |acc, g| {
+            games.iter().fold(0u32, |acc, g| {
                 if g.is_valid_for_constraints(&game_constraints) {
                     acc + g.id
                 } else {
                     acc
                 }
-            });
+            })
         }
         Part::Part2 => {
-            total = games.iter().fold(0u32, |acc, g| {
+            games.iter().fold(0u32, |acc, g| {
                 acc + g.min_constraints().power()
             })
         }
-    }
+    };
         
 
     format!("{}", total)
@@ -56,7 +55,7 @@ struct Game {
 impl Game {
     fn is_valid_for_constraints(&self, round_constraints: &Round) -> bool {
         self.rounds.iter()
-            .fold(true, |acc, cur| acc && cur.is_valid_for_constraints(round_constraints))
+            .all(|cur| cur.is_valid_for_constraints(round_constraints))
     }
 
     fn min_constraints(&self) -> Round {
@@ -218,7 +217,7 @@ Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
     let lines = EXAMPLE.split('\n')
-        .map(|item| String::from(item));
+        .map(String::from);
     
     let output = solve(Box::new(lines.clone()), Part::Part1);
     assert_eq!(output.as_str(), "8");
@@ -18,17 +18,14 @@ pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> Stri
 
     input.enumerate().for_each(|(y, line)| {
         let mut char_enumerator = line.chars().enumerate().peekable();
-        loop {
-            let (x, c) = match char_enumerator.peek() {
-                Some((x_val, c)) => (*x_val, *c),
-                None => break
-            };
+        while let Some((x_val, c_val)) = char_enumerator.peek() {
+            let (x, c) = (*x_val, *c_val);
 
             match c {
                 '.' => (),
                 '\n' => (),
                 _ if c.is_ascii_digit() => {
-                    let num = parse_num_from(&mut char_enumerator.by_ref());
+                    let num = parse_num_from(char_enumerator.by_ref());
                         nums.push(Number { 
                             val: num,
                             origin: Coord { x: x as u32, y: y as u32 }, 
@@ -178,7 +175,7 @@ fn test_input() {
 .664.598..";
 
     let lines = EXAMPLE.split('\n')
-        .map(|item| String::from(item));
+        .map(String::from);
     
     let output = solve(Box::new(lines.clone()), Part::Part1);
     assert_eq!(output.as_str(), "4361");
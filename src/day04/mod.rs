@@ -30,16 +30,16 @@ pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> Stri
                 let nxt = idx+1;
 
                 card_counts.entry(idx)
-                    .and_modify(|v| *v = *v+1 )
+                    .and_modify(|v| *v += 1 )
                     .or_insert(1);
 
                 let cur_count = *card_counts.get(&idx).expect("already added");                
-                (nxt..(nxt+win_count)).into_iter()
+                (nxt..(nxt+win_count))
                     .for_each(|i| {
                         if i > total_cards { return; }
 
                         card_counts.entry(i)
-                            .and_modify(|v| *v = *v+cur_count )
+                            .and_modify(|v| *v += cur_count )
                             .or_insert(cur_count);
                     })
             });
@@ -53,6 +53,7 @@ pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> Stri
 }
 
 struct Card {
+    #[allow(dead_code)]
     id: u32,
     winning_numbers: Vec<u32>,
     revealed_numbers: Vec<u32>,
@@ -70,13 +71,9 @@ impl Card {
     }
 
     fn num_winners(&self) -> usize {
-        self.revealed_numbers.iter().filter_map(|n| {
-            if self.winning_numbers.contains(n) {
-                Some(n)
-            } else { 
-                None 
-            }
-        }).count()
+        self.revealed_numbers.iter()
+            .filter(|n| self.winning_numbers.contains(n))
+            .count()
     }
 
 }
@@ -113,7 +110,7 @@ Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
     let lines = EXAMPLE.split('\n')
-        .map(|item| String::from(item));
+        .map(String::from);
     
     let output = solve(Box::new(lines.clone()), Part::Part1);
     assert_eq!(output.as_str(), "13");
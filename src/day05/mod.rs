@@ -0,0 +1,250 @@
+use crate::Part;
+
+use std::ops::Range;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, alpha1, line_ending, multispace1, space1},
+    error::VerboseError,
+    sequence::{preceded, separated_pair, terminated, tuple},
+    multi::separated_list1,
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let text = input.collect::<Vec<String>>().join("\n");
+    let (_, almanac) = almanac(text.as_str()).expect("valid almanac input");
+
+    let lowest = match part {
+        Part::Part1 => {
+            almanac.seeds.iter()
+                .map(|seed| almanac.location_of(*seed))
+                .min()
+        }
+        Part::Part2 => {
+            let seed_ranges: Vec<Range<u64>> = almanac.seeds.chunks(2)
+                .map(|pair| match pair {
+                    [start, len] => *start..(*start + *len),
+                    _ => panic!("seeds must come in `start length` pairs, `{}` is unpaired", pair[0]),
+                })
+                .collect();
+
+            almanac.locations_of(seed_ranges).iter()
+                .map(|r| r.start)
+                .min()
+        }
+    }.expect("must have at least one seed");
+
+    format!("{}", lowest)
+}
+
+#[derive(Debug, PartialEq)]
+struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    fn location_of(&self, seed: u64) -> u64 {
+        self.maps.iter()
+            .fold(seed, |value, map| map.convert(value))
+    }
+
+    fn locations_of(&self, seed_ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        self.maps.iter()
+            .fold(seed_ranges, |ranges, map| {
+                ranges.into_iter()
+                    .flat_map(|r| map.convert_range(r))
+                    .collect()
+            })
+    }
+}
+
+/// a single `x-to-y map:` block, with its entries kept sorted by source start
+#[derive(Debug, PartialEq)]
+struct Map {
+    entries: Vec<MapEntry>,
+}
+
+impl Map {
+    fn from(mut entries: Vec<MapEntry>) -> Map {
+        entries.sort_by_key(|e| e.src);
+        Map { entries }
+    }
+
+    fn convert(&self, value: u64) -> u64 {
+        self.entries.iter()
+            .find(|e| e.contains(value))
+            .map(|e| e.convert(value))
+            .unwrap_or(value)
+    }
+
+    /// splits `range` on every entry boundary it crosses, converting each piece.
+    /// any gaps not covered by an entry map straight through.
+    fn convert_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let mut converted = Vec::new();
+        let mut cur = range.start;
+
+        for entry in self.entries.iter() {
+            if cur >= range.end { break; }
+            if entry.src_end() <= cur { continue; }
+            if entry.src >= range.end { break; }
+
+            if cur < entry.src {
+                converted.push(cur..entry.src);
+                cur = entry.src;
+            }
+
+            let end = u64::min(range.end, entry.src_end());
+            converted.push(entry.convert(cur)..(entry.convert(end - 1) + 1));
+            cur = end;
+        }
+
+        if cur < range.end {
+            converted.push(cur..range.end);
+        }
+
+        converted
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct MapEntry {
+    dst: u64,
+    src: u64,
+    len: u64,
+}
+
+impl MapEntry {
+    fn src_end(&self) -> u64 {
+        self.src + self.len
+    }
+
+    fn contains(&self, value: u64) -> bool {
+        value >= self.src && value < self.src_end()
+    }
+
+    fn convert(&self, value: u64) -> u64 {
+        self.dst + (value - self.src)
+    }
+}
+
+fn almanac(input: &str) -> IResult<&str, Almanac, VerboseError<&str>> {
+    let (input, seeds) = terminated(seeds, multispace1)(input)?;
+    let (input, maps) = separated_list1(multispace1, map)(input)?;
+
+    Ok((
+        input,
+        Almanac {
+            seeds,
+            maps,
+        },
+    ))
+}
+
+// `seeds: 79 14 55 13`
+fn seeds(input: &str) -> IResult<&str, Vec<u64>, VerboseError<&str>> {
+    preceded(
+        tuple((tag("seeds:"), space1)),
+        separated_list1(space1, complete::u64),
+    )(input)
+}
+
+// `seed-to-soil map:` followed by its `dst src len` lines
+fn map(input: &str) -> IResult<&str, Map, VerboseError<&str>> {
+    let (input, _) = terminated(
+        tuple((separated_pair(alpha1, tag("-to-"), alpha1), tag(" map:"))),
+        line_ending,
+    )(input)?;
+    let (input, entries) = separated_list1(line_ending, map_entry)(input)?;
+
+    Ok((
+        input,
+        Map::from(entries),
+    ))
+}
+
+// `50 98 2`
+fn map_entry(input: &str) -> IResult<&str, MapEntry, VerboseError<&str>> {
+    let (input, (dst, _, src, _, len)) = tuple((
+            complete::u64, space1, complete::u64, space1, complete::u64,
+        ))(input)?;
+
+    Ok((
+        input,
+        MapEntry { dst, src, len },
+    ))
+}
+
+
+#[test]
+fn test_convert_range() {
+    let map = Map::from(vec![
+        MapEntry { dst: 52, src: 50, len: 48 },
+        MapEntry { dst: 50, src: 98, len: 2 },
+    ]);
+
+    assert_eq!(map.convert_range(79..93), vec![81..95]);
+    assert_eq!(map.convert_range(40..60), vec![40..50, 52..62]);
+    assert_eq!(map.convert_range(90..105), vec![92..100, 50..52, 100..105]);
+}
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "35");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "46");
+}
+
+#[test]
+#[should_panic(expected = "`start length` pairs")]
+fn test_unpaired_seed() {
+    const EXAMPLE: &str = r"seeds: 79 14 55
+
+seed-to-soil map:
+50 98 2";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    solve(Box::new(lines), Part::Part2);
+}
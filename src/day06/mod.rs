@@ -0,0 +1,126 @@
+use crate::Part;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, alpha1, space0, space1},
+    error::VerboseError,
+    sequence::{preceded, tuple},
+    multi::separated_list1,
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let rows: Vec<Vec<u64>> = input.filter_map(|line| {
+            let (_, values) = labeled_values(line.as_str()).ok()?;
+            Some(values)
+        }).collect();
+
+    let (times, distances) = match &rows[..] {
+        [times, distances] => (times, distances),
+        _ => panic!("input must have exactly a `Time:` and a `Distance:` line"),
+    };
+
+    let races: Vec<Race> = match part {
+        Part::Part1 => {
+            times.iter().zip(distances.iter())
+                .map(|(time, record)| Race { time: *time, record: *record })
+                .collect()
+        }
+        Part::Part2 => {
+            vec![Race { time: kerned(times), record: kerned(distances) }]
+        }
+    };
+
+    let total: u64 = races.iter().map(|r| r.winning_holds()).product();
+
+    format!("{}", total)
+}
+
+#[derive(Debug, PartialEq)]
+struct Race {
+    time: u64,
+    record: u64,
+}
+
+impl Race {
+    fn distance_for(&self, hold: u64) -> u64 {
+        hold * (self.time - hold)
+    }
+
+    fn beats_record(&self, hold: u64) -> bool {
+        hold <= self.time && self.distance_for(hold) > self.record
+    }
+
+    /// number of integer hold times `h` where `h * (time - h) > record`.
+    ///
+    /// the winning holds sit strictly between the roots of `h^2 - time*h + record = 0`,
+    /// so the smallest winner is found from the lower root (nudged to cover float error
+    /// and the case where a root lands exactly on an integer and only ties the record)
+    /// and the largest winner mirrors it around `time / 2`.
+    fn winning_holds(&self) -> u64 {
+        let time = self.time as f64;
+        let discriminant = time * time - 4.0 * self.record as f64;
+        if discriminant < 0.0 {
+            return 0;
+        }
+
+        let mut lowest = ((time - discriminant.sqrt()) / 2.0).floor().max(0.0) as u64;
+        while lowest > 0 && self.beats_record(lowest - 1) {
+            lowest -= 1;
+        }
+        while lowest <= self.time / 2 && !self.beats_record(lowest) {
+            lowest += 1;
+        }
+        if lowest > self.time / 2 {
+            return 0;
+        }
+
+        let highest = self.time - lowest;
+        highest - lowest + 1
+    }
+}
+
+/// joins each value's digits together, ie `[7, 15, 30]` becomes `71530`
+fn kerned(values: &[u64]) -> u64 {
+    values.iter()
+        .fold(0u64, |acc, v| {
+            let shift = 10u64.pow(format!("{v}").len() as u32);
+            acc * shift + v
+        })
+}
+
+// `Time:      7  15   30`
+fn labeled_values(input: &str) -> IResult<&str, Vec<u64>, VerboseError<&str>> {
+    preceded(
+        tuple((alpha1, tag(":"), space0)),
+        separated_list1(space1, complete::u64),
+    )(input)
+}
+
+
+#[test]
+fn test_winning_holds() {
+    assert_eq!(Race { time: 7, record: 9 }.winning_holds(), 4);
+    assert_eq!(Race { time: 15, record: 40 }.winning_holds(), 8);
+    // roots land exactly on 10 and 20, which only tie the record
+    assert_eq!(Race { time: 30, record: 200 }.winning_holds(), 9);
+    assert_eq!(Race { time: 4, record: 4 }.winning_holds(), 0);
+    assert_eq!(Race { time: 3, record: 100 }.winning_holds(), 0);
+}
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"Time:      7  15   30
+Distance:  9  40  200";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "288");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "71503");
+}
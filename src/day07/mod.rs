@@ -0,0 +1,203 @@
+use crate::Part;
+
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+};
+use nom::{
+    character::complete::{self, alphanumeric1, space1},
+    error::{VerboseError, ParseError, ErrorKind},
+    sequence::separated_pair,
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let jokers_wild = match part {
+        Part::Part1 => false,
+        Part::Part2 => true,
+    };
+
+    let mut hands: Vec<Hand> = input.filter_map(|line|  {
+            let (_, hand) = hand(line.as_str(), jokers_wild).ok()?;
+            Some(hand)
+        }).collect();
+
+    hands.sort();
+
+    let total: u64 = hands.iter().enumerate()
+        .map(|(idx, h)| (idx as u64 + 1) * h.bid as u64)
+        .sum();
+
+    format!("{}", total)
+}
+
+/// card strength, weakest to strongest.  `Joker` only shows up when `J`s are wild.
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card {
+    fn parse_from(value: char, jokers_wild: bool) -> Option<Self> {
+        match value {
+            '2' => Some(Self::Two),
+            '3' => Some(Self::Three),
+            '4' => Some(Self::Four),
+            '5' => Some(Self::Five),
+            '6' => Some(Self::Six),
+            '7' => Some(Self::Seven),
+            '8' => Some(Self::Eight),
+            '9' => Some(Self::Nine),
+            'T' => Some(Self::Ten),
+            'J' if jokers_wild => Some(Self::Joker),
+            'J' => Some(Self::Jack),
+            'Q' => Some(Self::Queen),
+            'K' => Some(Self::King),
+            'A' => Some(Self::Ace),
+            _ => None,
+        }
+    }
+}
+
+/// hand types, weakest to strongest.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// any jokers join whichever group is already largest, which is always the best use of them.
+    fn from(cards: &[Card; 5]) -> HandType {
+        let mut counts: HashMap<Card, u32> = HashMap::new();
+        let mut jokers = 0u32;
+        cards.iter().for_each(|c| match c {
+            Card::Joker => jokers += 1,
+            _ => { counts.entry(*c).and_modify(|v| *v += 1).or_insert(1); },
+        });
+
+        let mut groups: Vec<u32> = counts.into_values().collect();
+        groups.sort_unstable_by(|a, b| b.cmp(a));
+        match groups.first_mut() {
+            Some(largest) => *largest += jokers,
+            None => groups.push(jokers),
+        }
+
+        match groups[..] {
+            [5] => HandType::FiveOfAKind,
+            [4, 1] => HandType::FourOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [3, 1, 1] => HandType::ThreeOfAKind,
+            [2, 2, 1] => HandType::TwoPair,
+            [2, 1, 1, 1] => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Hand {
+    cards: [Card; 5],
+    kind: HandType,
+    bid: u32,
+}
+
+impl Hand {
+    fn from(cards: [Card; 5], bid: u32) -> Hand {
+        Hand { cards, kind: HandType::from(&cards), bid }
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Hand {}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.kind.cmp(&other.kind)
+            .then_with(|| self.cards.cmp(&other.cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// `32T3K 765`
+fn hand(input: &str, jokers_wild: bool) -> IResult<&str, Hand, VerboseError<&str>> {
+    let (remaining, (labels, bid)) = separated_pair(alphanumeric1, space1, complete::u32)(input)?;
+
+    let cards: Vec<Card> = labels.chars()
+        .filter_map(|c| Card::parse_from(c, jokers_wild))
+        .collect();
+    let cards: [Card; 5] = cards.try_into()
+        .map_err(|_| nom::Err::Error(VerboseError::from_error_kind(input, ErrorKind::Verify)))?;
+
+    Ok((
+        remaining,
+        Hand::from(cards, bid),
+    ))
+}
+
+
+#[test]
+fn test_hand_type() {
+    let kind = |s: &str, wild: bool| hand(format!("{s} 1").as_str(), wild).expect("valid hand").1.kind;
+
+    assert_eq!(kind("32T3K", false), HandType::OnePair);
+    assert_eq!(kind("KK677", false), HandType::TwoPair);
+    assert_eq!(kind("T55J5", false), HandType::ThreeOfAKind);
+    assert_eq!(kind("T55J5", true), HandType::FourOfAKind);
+    assert_eq!(kind("QQQJA", true), HandType::FourOfAKind);
+    assert_eq!(kind("JJJJJ", true), HandType::FiveOfAKind);
+    assert_eq!(kind("2345J", true), HandType::OnePair);
+
+    // jokers are the weakest card when breaking ties
+    let (_, jkkk2) = hand("JKKK2 1", true).unwrap();
+    let (_, qqqq2) = hand("QQQQ2 1", true).unwrap();
+    assert!(jkkk2 < qqqq2);
+}
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "6440");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "5905");
+}
@@ -0,0 +1,242 @@
+use crate::{math::lcm, Part};
+
+use std::collections::{HashMap, HashSet};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, space0},
+    error::VerboseError,
+    sequence::{delimited, separated_pair, tuple},
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let mut lines = input;
+    let instructions: Vec<Direction> = lines.next()
+        .expect("first line must be the instructions")
+        .chars()
+        .filter_map(Direction::parse_from)
+        .collect();
+
+    let mut network = Network::default();
+    lines.for_each(|line| {
+        if let Ok((_, (name, (left, right)))) = node(line.as_str()) {
+            network.insert(name, left, right);
+        }
+    });
+
+    let total = match part {
+        Part::Part1 => {
+            let start = network.id_of("AAA").expect("network must contain `AAA`");
+            network.steps_until(start, &instructions, |name| name == "ZZZ")
+        }
+        Part::Part2 => {
+            network.names.iter().enumerate()
+                .filter(|(_, name)| name.ends_with('A'))
+                .map(|(id, _)| network.cycle_length(id, &instructions))
+                .fold(1u64, lcm)
+        }
+    };
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn parse_from(value: char) -> Option<Self> {
+        match value {
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+/// node names are interned into ids, so walking the network is just indexing into `links`.
+#[derive(Debug, Default)]
+struct Network {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    links: Vec<(usize, usize)>,
+}
+
+impl Network {
+    fn id_of(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    fn intern(&mut self, name: &str) -> usize {
+        if let Some(id) = self.id_of(name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.names.push(String::from(name));
+        self.ids.insert(String::from(name), id);
+        self.links.push((id, id));
+        id
+    }
+
+    fn insert(&mut self, name: &str, left: &str, right: &str) {
+        let id = self.intern(name);
+        let left = self.intern(left);
+        let right = self.intern(right);
+        self.links[id] = (left, right);
+    }
+
+    fn next(&self, id: usize, direction: Direction) -> usize {
+        match direction {
+            Direction::Left => self.links[id].0,
+            Direction::Right => self.links[id].1,
+        }
+    }
+
+    /// counts the steps from `start` until landing on a node accepted by `is_end`.
+    fn steps_until(&self, start: usize, instructions: &[Direction], is_end: impl Fn(&str) -> bool) -> u64 {
+        self.walk(start, instructions, 0, is_end).1
+    }
+
+    /// how often a ghost starting at `start` lands on a `..Z` node.
+    ///
+    /// only holds up when, after first reaching its `..Z` node, the ghost keeps reaching
+    /// that same node after the same number of steps.  that's checked here, rather than
+    /// assumed: laps are walked until the ghost is back on that node at a place in the
+    /// instructions it has already been, from where everything repeats.
+    fn cycle_length(&self, start: usize, instructions: &[Direction]) -> u64 {
+        let is_end = |name: &str| name.ends_with('Z');
+
+        let (end, steps) = self.walk(start, instructions, 0, is_end);
+        let mut phase = steps as usize % instructions.len();
+        let mut seen = HashSet::new();
+
+        while seen.insert(phase) {
+            let (again, lap) = self.walk(end, instructions, phase, is_end);
+            assert!(
+                again == end && lap == steps,
+                "ghost from `{}` doesn't loop: `{}` after {} steps, then `{}` after {} more",
+                self.names[start], self.names[end], steps, self.names[again], lap,
+            );
+            phase = (phase + lap as usize) % instructions.len();
+        }
+
+        steps
+    }
+
+    /// walks from `start`, beginning `offset` steps into the instructions, until landing
+    /// on a node accepted by `is_end`.  returns that node along with the steps taken.
+    fn walk(&self, start: usize, instructions: &[Direction], offset: usize, is_end: impl Fn(&str) -> bool) -> (usize, u64) {
+        let mut cur = start;
+        let mut steps = 0u64;
+
+        for direction in instructions.iter().cycle().skip(offset % instructions.len()) {
+            cur = self.next(cur, *direction);
+            steps += 1;
+
+            if is_end(self.names[cur].as_str()) {
+                break;
+            }
+        }
+
+        (cur, steps)
+    }
+}
+
+// `AAA = (BBB, CCC)`
+fn node(input: &str) -> IResult<&str, (&str, (&str, &str)), VerboseError<&str>> {
+    separated_pair(
+        alphanumeric1,
+        tuple((space0, tag("="), space0)),
+        delimited(
+            tag("("),
+            separated_pair(alphanumeric1, tuple((tag(","), space0)), alphanumeric1),
+            tag(")"),
+        ),
+    )(input)
+}
+
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE_1: &str = r"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+    const EXAMPLE_2: &str = r"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    const EXAMPLE_3: &str = r"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    let lines = EXAMPLE_1.split('\n')
+        .map(String::from);
+    let output = solve(Box::new(lines), Part::Part1);
+    assert_eq!(output.as_str(), "2");
+
+    let lines = EXAMPLE_2.split('\n')
+        .map(String::from);
+    let output = solve(Box::new(lines), Part::Part1);
+    assert_eq!(output.as_str(), "6");
+
+    let lines = EXAMPLE_3.split('\n')
+        .map(String::from);
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "6");
+}
+
+#[test]
+#[should_panic(expected = "doesn't loop")]
+fn test_cycle_length_checked() {
+    // `11Z` is first reached after 1 step, but only comes back around 3 steps later
+    const EXAMPLE: &str = r"LR
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11B, 11Z)";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    solve(Box::new(lines), Part::Part2);
+}
+
+#[test]
+#[should_panic(expected = "doesn't loop")]
+fn test_cycle_length_checks_every_phase() {
+    // `11Z` comes back 1 step later from where it's first reached in the instructions,
+    // but takes 4 steps from the next place it's at
+    const EXAMPLE: &str = r"LLR
+
+11A = (11Z, 11Z)
+11Z = (11Z, 11X)
+11X = (11X, 11Z)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    solve(Box::new(lines), Part::Part2);
+}
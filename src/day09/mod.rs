@@ -0,0 +1,89 @@
+use crate::Part;
+
+use nom::{
+    character::complete::{self, space1},
+    error::VerboseError,
+    multi::separated_list1,
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let histories: Vec<Vec<i64>> = input.filter_map(|line|  {
+            let (_, values) = history(line.as_str()).ok()?;
+            Some(values)
+        }).collect();
+
+    let total: i64 = match part {
+        Part::Part1 => histories.iter().map(|h| extrapolate_next(h)).sum(),
+        Part::Part2 => histories.iter().map(|h| extrapolate_prev(h)).sum(),
+    };
+
+    format!("{}", total)
+}
+
+/// builds each row of differences until a row is all zeros.
+fn difference_pyramid(values: &[i64]) -> Vec<Vec<i64>> {
+    let mut rows = vec![values.to_vec()];
+
+    loop {
+        let last = rows.last().expect("always has at least one row");
+        if last.iter().all(|v| *v == 0) {
+            break;
+        }
+
+        let diffs: Vec<i64> = last.windows(2)
+            .map(|w| w[1] - w[0])
+            .collect();
+        rows.push(diffs);
+    }
+
+    rows
+}
+
+fn extrapolate_next(values: &[i64]) -> i64 {
+    difference_pyramid(values).iter().rev()
+        .fold(0i64, |below, row| row.last().unwrap_or(&0) + below)
+}
+
+fn extrapolate_prev(values: &[i64]) -> i64 {
+    difference_pyramid(values).iter().rev()
+        .fold(0i64, |below, row| row.first().unwrap_or(&0) - below)
+}
+
+// `10 13 16 21 30 45` or `-3 -1 2`
+fn history(input: &str) -> IResult<&str, Vec<i64>, VerboseError<&str>> {
+    separated_list1(space1, complete::i64)(input)
+}
+
+
+#[test]
+fn test_parser() {
+    let (remaining, parsed) = history("4 -2 -10 0").expect("valid history");
+    assert_eq!(remaining, "");
+    assert_eq!(parsed, vec![4, -2, -10, 0]);
+}
+
+#[test]
+fn test_extrapolate() {
+    assert_eq!(extrapolate_next(&[10, 13, 16, 21, 30, 45]), 68);
+    assert_eq!(extrapolate_prev(&[10, 13, 16, 21, 30, 45]), 5);
+    assert_eq!(extrapolate_next(&[-1, -3, -5]), -7);
+}
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "114");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "2");
+}
@@ -0,0 +1,244 @@
+use crate::Part;
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let maze = Maze::from(input);
+    let path = maze.main_loop();
+
+    let total = match part {
+        Part::Part1 => path.len() / 2,
+        Part::Part2 => enclosed_tiles(&path),
+    };
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    fn opposite(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    fn bit(&self) -> u8 {
+        match self {
+            Direction::North => 0b0001,
+            Direction::East => 0b0010,
+            Direction::South => 0b0100,
+            Direction::West => 0b1000,
+        }
+    }
+}
+
+/// each tile is stored as a bitmask of the directions its pipe connects to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Tile(u8);
+
+impl Tile {
+    fn parse_from(value: char) -> Tile {
+        let dirs: &[Direction] = match value {
+            '|' => &[Direction::North, Direction::South],
+            '-' => &[Direction::East, Direction::West],
+            'L' => &[Direction::North, Direction::East],
+            'J' => &[Direction::North, Direction::West],
+            '7' => &[Direction::South, Direction::West],
+            'F' => &[Direction::South, Direction::East],
+            _ => &[],
+        };
+        Tile(dirs.iter().fold(0u8, |acc, d| acc | d.bit()))
+    }
+
+    fn connects(&self, direction: Direction) -> bool {
+        self.0 & direction.bit() != 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Coord {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug)]
+struct Maze {
+    tiles: Vec<Vec<Tile>>,
+    start: Coord,
+}
+
+impl Maze {
+    fn from(input: Box<dyn Iterator<Item = String>>) -> Maze {
+        let mut start = None;
+        let tiles: Vec<Vec<Tile>> = input
+            .filter(|line| !line.is_empty())
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars().enumerate().map(|(x, c)| {
+                    if c == 'S' {
+                        start = Some(Coord { x, y });
+                    }
+                    Tile::parse_from(c)
+                }).collect()
+            }).collect();
+
+        let start = start.expect("maze must have a starting `S` tile");
+        let mut maze = Maze { tiles, start };
+        maze.tiles[start.y][start.x] = maze.hidden_start_tile();
+        maze
+    }
+
+    fn tile(&self, coord: Coord) -> Tile {
+        self.tiles[coord.y][coord.x]
+    }
+
+    fn step(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+        let Coord { x, y } = coord;
+        let next = match direction {
+            Direction::North => Coord { x, y: y.checked_sub(1)? },
+            Direction::East => Coord { x: x + 1, y },
+            Direction::South => Coord { x, y: y + 1 },
+            Direction::West => Coord { x: x.checked_sub(1)?, y },
+        };
+
+        let row = self.tiles.get(next.y)?;
+        row.get(next.x)?;
+        Some(next)
+    }
+
+    /// the pipe under `S` connects to every neighbour whose pipe points back at it.
+    fn hidden_start_tile(&self) -> Tile {
+        let bits = Direction::ALL.iter()
+            .filter(|d| {
+                self.step(self.start, **d)
+                    .is_some_and(|c| self.tile(c).connects(d.opposite()))
+            })
+            .fold(0u8, |acc, d| acc | d.bit());
+        Tile(bits)
+    }
+
+    /// every coord along the main loop, in walking order starting at `S`.
+    fn main_loop(&self) -> Vec<Coord> {
+        let mut heading = *Direction::ALL.iter()
+            .find(|d| self.tile(self.start).connects(**d))
+            .expect("`S` must be part of a loop");
+
+        let mut path = vec![self.start];
+        let mut cur = self.step(self.start, heading).expect("loop stays within the maze");
+        while cur != self.start {
+            path.push(cur);
+
+            let came_from = heading.opposite();
+            heading = *Direction::ALL.iter()
+                .find(|d| **d != came_from && self.tile(cur).connects(**d))
+                .expect("loop pipes always have an exit");
+            cur = self.step(cur, heading).expect("loop stays within the maze");
+        }
+
+        path
+    }
+}
+
+/// shoelace formula gives the loop's area, then Pick's theorem
+/// (`A = i + b/2 - 1`) backs out the number of interior tiles.
+fn enclosed_tiles(path: &[Coord]) -> usize {
+    let twice_area: i64 = path.iter()
+        .zip(path.iter().cycle().skip(1))
+        .map(|(a, b)| (a.x as i64 * b.y as i64) - (b.x as i64 * a.y as i64))
+        .sum();
+    let area = twice_area.unsigned_abs() as usize / 2;
+
+    area + 1 - path.len() / 2
+}
+
+
+#[test]
+// sanity check vs example input
+fn test_input_part1() {
+    const EXAMPLE_1: &str = r"-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+
+    const EXAMPLE_2: &str = r"7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+    let lines = EXAMPLE_1.split('\n')
+        .map(String::from);
+    let output = solve(Box::new(lines), Part::Part1);
+    assert_eq!(output.as_str(), "4");
+
+    let lines = EXAMPLE_2.split('\n')
+        .map(String::from);
+    let output = solve(Box::new(lines), Part::Part1);
+    assert_eq!(output.as_str(), "8");
+}
+
+#[test]
+fn test_input_part2() {
+    const EXAMPLE_1: &str = r"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+    // the loop squeezes between pipes, but those tiles are still outside
+    const EXAMPLE_2: &str = r"..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+
+    const EXAMPLE_3: &str = r".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+    const EXAMPLE_4: &str = r"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    let cases = [(EXAMPLE_1, "4"), (EXAMPLE_2, "4"), (EXAMPLE_3, "8"), (EXAMPLE_4, "10")];
+    for (example, expected) in cases {
+        let lines = example.split('\n')
+            .map(String::from);
+        let output = solve(Box::new(lines), Part::Part2);
+        assert_eq!(output.as_str(), expected);
+    }
+}
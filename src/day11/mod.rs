@@ -0,0 +1,91 @@
+use crate::Part;
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let galaxies = parse_galaxies(input);
+
+    let total = match part {
+        Part::Part1 => summed_distances(&galaxies, 2),
+        Part::Part2 => summed_distances(&galaxies, 1_000_000),
+    };
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Coord {
+    x: u64,
+    y: u64,
+}
+
+fn parse_galaxies(input: Box<dyn Iterator<Item = String>>) -> Vec<Coord> {
+    input.enumerate()
+        .flat_map(|(y, line)| {
+            line.char_indices()
+                .filter(|(_, c)| *c == '#')
+                .map(|(x, _)| Coord { x: x as u64, y: y as u64 })
+                .collect::<Vec<Coord>>()
+        })
+        .collect()
+}
+
+/// sum of the shortest paths between every pair of galaxies, where each
+/// empty row/column is replaced by `expansion` empty rows/columns.
+///
+/// manhattan distance splits per axis, so each axis is handled on its own.
+fn summed_distances(galaxies: &[Coord], expansion: u64) -> u64 {
+    let xs: Vec<u64> = galaxies.iter().map(|g| g.x).collect();
+    let ys: Vec<u64> = galaxies.iter().map(|g| g.y).collect();
+
+    summed_axis_distances(xs, expansion) + summed_axis_distances(ys, expansion)
+}
+
+fn summed_axis_distances(mut coords: Vec<u64>, expansion: u64) -> u64 {
+    coords.sort_unstable();
+
+    // shift each coord by the number of empty lines before it
+    let mut empty_lines = 0u64;
+    let mut prev = coords.first().copied().unwrap_or(0);
+    let expanded = coords.iter().map(|c| {
+        empty_lines += c.saturating_sub(prev + 1);
+        prev = *c;
+        c + empty_lines * (expansion - 1)
+    });
+
+    // with the coords sorted, each one is `idx` galaxies further along
+    // than every galaxy before it, minus their running total.
+    let mut preceding_sum = 0u64;
+    expanded.enumerate()
+        .map(|(idx, c)| {
+            let dist = c * idx as u64 - preceding_sum;
+            preceding_sum += c;
+            dist
+        })
+        .sum()
+}
+
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "374");
+
+    let galaxies = parse_galaxies(Box::new(lines));
+    assert_eq!(summed_distances(&galaxies, 10), 1030);
+    assert_eq!(summed_distances(&galaxies, 100), 8410);
+}
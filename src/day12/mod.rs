@@ -0,0 +1,158 @@
+use crate::Part;
+
+use nom::{
+    bytes::complete::{is_a, tag},
+    character::complete::{self, space1},
+    error::VerboseError,
+    sequence::separated_pair,
+    multi::separated_list1,
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let records: Vec<Record> = input.filter_map(|line|  {
+            let (_, record) = record(line.as_str()).ok()?;
+            Some(record)
+        }).collect();
+
+    let total: u64 = match part {
+        Part::Part1 => records.iter().map(|r| r.arrangements()).sum(),
+        Part::Part2 => records.iter().map(|r| r.unfolded(5).arrangements()).sum(),
+    };
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Spring {
+    fn parse_from(value: char) -> Option<Self> {
+        match value {
+            '.' => Some(Self::Operational),
+            '#' => Some(Self::Damaged),
+            '?' => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Record {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    /// repeats the record `times` over, joining the springs with an extra `?`.
+    fn unfolded(&self, times: usize) -> Record {
+        let mut springs = Vec::with_capacity((self.springs.len() + 1) * times);
+        for idx in 0..times {
+            if idx > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+
+        Record {
+            springs,
+            groups: self.groups.repeat(times),
+        }
+    }
+
+    /// counts the valid arrangements with a table over `(position, group index)`,
+    /// where `ways[pos][group]` is the number of ways to fit `groups[group..]`
+    /// into `springs[pos..]`.  filled back to front so each cell only looks ahead.
+    fn arrangements(&self) -> u64 {
+        let len = self.springs.len();
+        let num_groups = self.groups.len();
+
+        // `ways[len + 1]` is a padding row, so a group ending right at `len`
+        // can always skip its trailing separator.
+        let mut ways = vec![vec![0u64; num_groups + 1]; len + 2];
+        ways[len][num_groups] = 1;
+        ways[len + 1][num_groups] = 1;
+
+        for pos in (0..len).rev() {
+            for group in 0..=num_groups {
+                let spring = self.springs[pos];
+                let mut count = 0;
+
+                if spring != Spring::Damaged {
+                    count += ways[pos + 1][group];
+                }
+
+                if spring != Spring::Operational && group < num_groups && self.fits(pos, self.groups[group]) {
+                    count += ways[pos + self.groups[group] + 1][group + 1];
+                }
+
+                ways[pos][group] = count;
+            }
+        }
+
+        ways[0][0]
+    }
+
+    /// whether a damaged run of `size` can start at `pos`, with no damaged spring right after it.
+    fn fits(&self, pos: usize, size: usize) -> bool {
+        let end = pos + size;
+        end <= self.springs.len()
+            && self.springs[pos..end].iter().all(|s| *s != Spring::Operational)
+            && self.springs.get(end) != Some(&Spring::Damaged)
+    }
+}
+
+// `???.### 1,1,3`
+fn record(input: &str) -> IResult<&str, Record, VerboseError<&str>> {
+    let (input, (springs, groups)) = separated_pair(
+            is_a(".#?"),
+            space1,
+            separated_list1(tag(","), complete::u32),
+        )(input)?;
+
+    Ok((
+        input,
+        Record {
+            springs: springs.chars().filter_map(Spring::parse_from).collect(),
+            groups: groups.into_iter().map(|g| g as usize).collect(),
+        },
+    ))
+}
+
+
+#[test]
+fn test_arrangements() {
+    let count = |s: &str| record(s).expect("valid record").1.arrangements();
+
+    assert_eq!(count("???.### 1,1,3"), 1);
+    assert_eq!(count(".??..??...?##. 1,1,3"), 4);
+    assert_eq!(count("?#?#?#?#?#?#?#? 1,3,1,6"), 1);
+    assert_eq!(count("?###???????? 3,2,1"), 10);
+    assert_eq!(count("# 1"), 1);
+    assert_eq!(count("## 1"), 0);
+}
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "21");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "525152");
+}
@@ -0,0 +1,118 @@
+use crate::Part;
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let patterns = parse_patterns(input);
+
+    let smudges = match part {
+        Part::Part1 => 0,
+        Part::Part2 => 1,
+    };
+
+    let total: usize = patterns.iter()
+        .map(|p| p.summary(smudges))
+        .sum();
+
+    format!("{}", total)
+}
+
+/// each row and column is stored as a bitmask, where a `#` is a set bit.
+#[derive(Debug, PartialEq)]
+struct Pattern {
+    rows: Vec<u64>,
+    cols: Vec<u64>,
+}
+
+impl Pattern {
+    fn from(lines: &[String]) -> Pattern {
+        let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+        let mut rows = vec![0u64; lines.len()];
+        let mut cols = vec![0u64; width];
+
+        lines.iter().enumerate().for_each(|(y, line)| {
+            line.char_indices()
+                .filter(|(_, c)| *c == '#')
+                .for_each(|(x, _)| {
+                    rows[y] |= 1 << x;
+                    cols[x] |= 1 << y;
+                });
+        });
+
+        Pattern { rows, cols }
+    }
+
+    /// columns left of a vertical mirror, or 100 times the rows above a horizontal one.
+    fn summary(&self, smudges: u32) -> usize {
+        if let Some(cols) = reflection(&self.cols, smudges) {
+            return cols;
+        }
+        if let Some(rows) = reflection(&self.rows, smudges) {
+            return rows * 100;
+        }
+        panic!("every pattern must have a line of reflection");
+    }
+}
+
+/// finds the mirror line where exactly `smudges` bits differ across it,
+/// returning the number of lines before it.
+fn reflection(lines: &[u64], smudges: u32) -> Option<usize> {
+    (1..lines.len()).find(|split| {
+        let (before, after) = lines.split_at(*split);
+        let mismatched: u32 = before.iter().rev()
+            .zip(after.iter())
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum();
+        mismatched == smudges
+    })
+}
+
+fn parse_patterns(input: Box<dyn Iterator<Item = String>>) -> Vec<Pattern> {
+    let mut patterns = Vec::new();
+    let mut cur: Vec<String> = Vec::new();
+
+    for line in input {
+        if line.trim().is_empty() {
+            if !cur.is_empty() {
+                patterns.push(Pattern::from(&cur));
+                cur.clear();
+            }
+            continue;
+        }
+        cur.push(line);
+    }
+    if !cur.is_empty() {
+        patterns.push(Pattern::from(&cur));
+    }
+
+    patterns
+}
+
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "405");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "400");
+}
@@ -0,0 +1,214 @@
+use crate::Part;
+
+use std::collections::HashMap;
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let mut platform = Platform::from(input);
+
+    let total = match part {
+        Part::Part1 => {
+            platform.tilt(Direction::North);
+            platform.north_load()
+        }
+        Part::Part2 => {
+            platform.spin_cycles(1_000_000_000);
+            platform.north_load()
+        }
+    };
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    North,
+    West,
+    South,
+    East,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum Tile {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl Tile {
+    fn parse_from(value: char) -> Tile {
+        match value {
+            'O' => Tile::Round,
+            '#' => Tile::Cube,
+            _ => Tile::Empty,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct Platform {
+    tiles: Vec<Vec<Tile>>,
+}
+
+impl Platform {
+    fn from(input: Box<dyn Iterator<Item = String>>) -> Platform {
+        let tiles = input
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().map(Tile::parse_from).collect())
+            .collect();
+
+        Platform { tiles }
+    }
+
+    fn height(&self) -> usize {
+        self.tiles.len()
+    }
+
+    fn width(&self) -> usize {
+        self.tiles.first().map(|row| row.len()).unwrap_or(0)
+    }
+
+    /// rolls every round rock as far as it can go towards `direction`.
+    ///
+    /// each line along the tilt is swept once from the far edge, tracking
+    /// the next free slot a rock would come to rest in.
+    fn tilt(&mut self, direction: Direction) {
+        let (lines, len) = match direction {
+            Direction::North | Direction::South => (self.width(), self.height()),
+            Direction::West | Direction::East => (self.height(), self.width()),
+        };
+
+        for line in 0..lines {
+            let mut free_slot = 0;
+            for offset in 0..len {
+                let (x, y) = self.coord_of(direction, line, offset, len);
+                match self.tiles[y][x] {
+                    Tile::Cube => free_slot = offset + 1,
+                    Tile::Round => {
+                        self.tiles[y][x] = Tile::Empty;
+                        let (fx, fy) = self.coord_of(direction, line, free_slot, len);
+                        self.tiles[fy][fx] = Tile::Round;
+                        free_slot += 1;
+                    }
+                    Tile::Empty => (),
+                }
+            }
+        }
+    }
+
+    /// maps `offset` steps away from the edge being tilted towards onto an `(x, y)` coord.
+    fn coord_of(&self, direction: Direction, line: usize, offset: usize, len: usize) -> (usize, usize) {
+        match direction {
+            Direction::North => (line, offset),
+            Direction::South => (line, len - 1 - offset),
+            Direction::West => (offset, line),
+            Direction::East => (len - 1 - offset, line),
+        }
+    }
+
+    fn spin_cycle(&mut self) {
+        self.tilt(Direction::North);
+        self.tilt(Direction::West);
+        self.tilt(Direction::South);
+        self.tilt(Direction::East);
+    }
+
+    /// runs `count` spin cycles, skipping ahead once the platform starts repeating itself.
+    fn spin_cycles(&mut self, count: usize) {
+        let mut seen: HashMap<Platform, usize> = HashMap::new();
+
+        let mut cycle = 0;
+        while cycle < count {
+            if let Some(first_seen) = seen.insert(self.clone(), cycle) {
+                let period = cycle - first_seen;
+                let remaining = (count - cycle) % period;
+                for _ in 0..remaining {
+                    self.spin_cycle();
+                }
+                return;
+            }
+
+            self.spin_cycle();
+            cycle += 1;
+        }
+    }
+
+    fn north_load(&self) -> usize {
+        let height = self.height();
+        self.tiles.iter().enumerate()
+            .map(|(y, row)| {
+                row.iter().filter(|t| **t == Tile::Round).count() * (height - y)
+            })
+            .sum()
+    }
+}
+
+
+#[cfg(test)]
+const EXAMPLE: &str = r"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+#[test]
+fn test_spin_cycle() {
+    const AFTER_1: &str = r".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....";
+
+    const AFTER_2: &str = r".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O";
+
+    const AFTER_3: &str = r".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O";
+
+    let parse = |s: &'static str| Platform::from(Box::new(s.split('\n').map(String::from)));
+
+    let mut platform = parse(EXAMPLE);
+    for expected in [AFTER_1, AFTER_2, AFTER_3] {
+        platform.spin_cycle();
+        assert_eq!(platform, parse(expected));
+    }
+}
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "136");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "64");
+}
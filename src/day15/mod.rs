@@ -0,0 +1,122 @@
+use crate::Part;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1},
+    combinator::map,
+    error::VerboseError,
+    sequence::{pair, preceded},
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let sequence = input.collect::<Vec<String>>().concat();
+    let steps = sequence.split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty());
+
+    let total = match part {
+        Part::Part1 => {
+            steps.map(|s| hash(s) as usize).sum()
+        }
+        Part::Part2 => {
+            let mut boxes = LensBoxes::new();
+            steps.for_each(|s| {
+                let (_, (label, op)) = step(s).expect("valid initialization step");
+                boxes.apply(label, op);
+            });
+            boxes.focusing_power()
+        }
+    };
+
+    format!("{}", total)
+}
+
+/// the Holiday ASCII String Helper algorithm.
+fn hash(value: &str) -> u8 {
+    value.bytes()
+        .fold(0u8, |acc, b| acc.wrapping_add(b).wrapping_mul(17))
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Operation {
+    Remove,
+    Insert(u8),
+}
+
+#[derive(Debug)]
+struct Lens {
+    label: String,
+    focal_length: u8,
+}
+
+#[derive(Debug)]
+struct LensBoxes {
+    boxes: Vec<Vec<Lens>>,
+}
+
+impl LensBoxes {
+    fn new() -> LensBoxes {
+        LensBoxes {
+            boxes: (0..256).map(|_| Vec::new()).collect(),
+        }
+    }
+
+    fn apply(&mut self, label: &str, op: Operation) {
+        let lenses = &mut self.boxes[hash(label) as usize];
+        let existing = lenses.iter().position(|l| l.label == label);
+
+        match (op, existing) {
+            (Operation::Remove, Some(idx)) => { lenses.remove(idx); },
+            (Operation::Remove, None) => (),
+            (Operation::Insert(focal_length), Some(idx)) => lenses[idx].focal_length = focal_length,
+            (Operation::Insert(focal_length), None) => lenses.push(Lens { label: String::from(label), focal_length }),
+        }
+    }
+
+    fn focusing_power(&self) -> usize {
+        self.boxes.iter().enumerate()
+            .flat_map(|(box_idx, lenses)| {
+                lenses.iter().enumerate()
+                    .map(move |(slot, lens)| (box_idx + 1) * (slot + 1) * lens.focal_length as usize)
+            })
+            .sum()
+    }
+}
+
+// `rn=1` or `cm-`
+fn step(input: &str) -> IResult<&str, (&str, Operation), VerboseError<&str>> {
+    pair(
+        alpha1,
+        alt((
+            map(tag("-"), |_| Operation::Remove),
+            map(preceded(tag("="), complete::u8), Operation::Insert),
+        )),
+    )(input)
+}
+
+
+#[test]
+fn test_hash() {
+    assert_eq!(hash("HASH"), 52);
+    assert_eq!(hash("rn=1"), 30);
+    assert_eq!(hash("rn"), 0);
+    assert_eq!(hash("qp"), 1);
+}
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "1320");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "145");
+}
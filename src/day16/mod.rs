@@ -0,0 +1,203 @@
+use crate::Part;
+
+use std::thread;
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let contraption = Contraption::from(input);
+
+    let total = match part {
+        Part::Part1 => contraption.energized(Beam { x: 0, y: 0, heading: Direction::East }),
+        Part::Part2 => contraption.most_energized(),
+    };
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    fn bit(&self) -> u8 {
+        match self {
+            Direction::North => 0b0001,
+            Direction::East => 0b0010,
+            Direction::South => 0b0100,
+            Direction::West => 0b1000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    /// `/`
+    MirrorForward,
+    /// `\`
+    MirrorBack,
+    /// `|`
+    SplitterVertical,
+    /// `-`
+    SplitterHorizontal,
+}
+
+impl Tile {
+    fn parse_from(value: char) -> Tile {
+        match value {
+            '/' => Tile::MirrorForward,
+            '\\' => Tile::MirrorBack,
+            '|' => Tile::SplitterVertical,
+            '-' => Tile::SplitterHorizontal,
+            _ => Tile::Empty,
+        }
+    }
+
+    /// the heading(s) a beam leaves this tile with.
+    fn redirect(&self, heading: Direction) -> (Direction, Option<Direction>) {
+        use Direction::*;
+        match (self, heading) {
+            (Tile::MirrorForward, North) => (East, None),
+            (Tile::MirrorForward, East) => (North, None),
+            (Tile::MirrorForward, South) => (West, None),
+            (Tile::MirrorForward, West) => (South, None),
+
+            (Tile::MirrorBack, North) => (West, None),
+            (Tile::MirrorBack, West) => (North, None),
+            (Tile::MirrorBack, South) => (East, None),
+            (Tile::MirrorBack, East) => (South, None),
+
+            (Tile::SplitterVertical, East | West) => (North, Some(South)),
+            (Tile::SplitterHorizontal, North | South) => (East, Some(West)),
+
+            _ => (heading, None),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Beam {
+    x: usize,
+    y: usize,
+    heading: Direction,
+}
+
+#[derive(Debug)]
+struct Contraption {
+    tiles: Vec<Tile>,
+    width: usize,
+    height: usize,
+}
+
+impl Contraption {
+    fn from(input: Box<dyn Iterator<Item = String>>) -> Contraption {
+        let rows: Vec<Vec<Tile>> = input
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().map(Tile::parse_from).collect())
+            .collect();
+
+        let height = rows.len();
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+        Contraption { tiles: rows.concat(), width, height }
+    }
+
+    fn advance(&self, x: usize, y: usize, heading: Direction) -> Option<Beam> {
+        let (x, y) = match heading {
+            Direction::North => (x, y.checked_sub(1)?),
+            Direction::East => (x + 1, y),
+            Direction::South => (x, y + 1),
+            Direction::West => (x.checked_sub(1)?, y),
+        };
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(Beam { x, y, heading })
+    }
+
+    /// number of tiles a beam entering at `start` passes through.
+    ///
+    /// every tile tracks a bitset of the headings beams have already entered it
+    /// with, so beams caught in a loop stop as soon as they repeat themselves.
+    fn energized(&self, start: Beam) -> usize {
+        let mut visited = vec![0u8; self.tiles.len()];
+        let mut beams = vec![start];
+
+        while let Some(Beam { x, y, heading }) = beams.pop() {
+            let idx = y * self.width + x;
+            if visited[idx] & heading.bit() != 0 {
+                continue;
+            }
+            visited[idx] |= heading.bit();
+
+            let (first, second) = self.tiles[idx].redirect(heading);
+            beams.extend(self.advance(x, y, first));
+            if let Some(second) = second {
+                beams.extend(self.advance(x, y, second));
+            }
+        }
+
+        visited.iter().filter(|v| **v != 0).count()
+    }
+
+    fn edge_entries(&self) -> Vec<Beam> {
+        let (w, h) = (self.width, self.height);
+        (0..w).flat_map(|x| [
+                Beam { x, y: 0, heading: Direction::South },
+                Beam { x, y: h - 1, heading: Direction::North },
+            ])
+            .chain((0..h).flat_map(|y| [
+                Beam { x: 0, y, heading: Direction::East },
+                Beam { x: w - 1, y, heading: Direction::West },
+            ]))
+            .collect()
+    }
+
+    /// tries every edge entry, sweeping the edges across the available cores.
+    fn most_energized(&self) -> usize {
+        let entries = self.edge_entries();
+        let workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        let chunk_size = entries.len().div_ceil(workers).max(1);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = entries.chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || {
+                    chunk.iter().map(|b| self.energized(*b)).max().unwrap_or(0)
+                }))
+                .collect();
+
+            handles.into_iter()
+                .map(|h| h.join().expect("edge sweep worker panicked"))
+                .max()
+                .unwrap_or(0)
+        })
+    }
+}
+
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "46");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "51");
+}
@@ -0,0 +1,190 @@
+use crate::Part;
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let city = City::from(input);
+
+    let crucible = match part {
+        Part::Part1 => Crucible { min_run: 0, max_run: 3 },
+        Part::Part2 => Crucible { min_run: 4, max_run: 10 },
+    };
+
+    let total = city.least_heat_loss(crucible).expect("must be able to reach the factory");
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    fn opposite(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+/// how many blocks a crucible must/may travel in a straight line.
+#[derive(Debug, Clone, Copy)]
+struct Crucible {
+    min_run: usize,
+    max_run: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct State {
+    x: usize,
+    y: usize,
+    heading: Direction,
+    run: usize,
+}
+
+#[derive(Debug)]
+struct City {
+    heat_loss: Vec<Vec<u32>>,
+}
+
+impl City {
+    fn from(input: Box<dyn Iterator<Item = String>>) -> City {
+        let heat_loss = input
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().filter_map(|c| c.to_digit(10)).collect())
+            .collect();
+
+        City { heat_loss }
+    }
+
+    fn height(&self) -> usize {
+        self.heat_loss.len()
+    }
+
+    fn width(&self) -> usize {
+        self.heat_loss.first().map(|r| r.len()).unwrap_or(0)
+    }
+
+    fn step(&self, x: usize, y: usize, heading: Direction) -> Option<(usize, usize)> {
+        let (x, y) = match heading {
+            Direction::North => (x, y.checked_sub(1)?),
+            Direction::East => (x + 1, y),
+            Direction::South => (x, y + 1),
+            Direction::West => (x.checked_sub(1)?, y),
+        };
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    fn state_idx(&self, state: &State, crucible: Crucible) -> usize {
+        ((state.y * self.width() + state.x) * 4 + state.heading as usize) * (crucible.max_run + 1) + state.run
+    }
+
+    /// dijkstra over `(position, heading, run length)`, from the top-left to the bottom-right.
+    /// the crucible can only turn or stop once its run reaches `min_run`, and must turn at `max_run`.
+    fn least_heat_loss(&self, crucible: Crucible) -> Option<u32> {
+        let (width, height) = (self.width(), self.height());
+        let mut best = vec![u32::MAX; width * height * 4 * (crucible.max_run + 1)];
+        let mut queue = BinaryHeap::new();
+
+        for heading in [Direction::East, Direction::South] {
+            let start = State { x: 0, y: 0, heading, run: 0 };
+            best[self.state_idx(&start, crucible)] = 0;
+            queue.push(Reverse((0u32, start)));
+        }
+
+        while let Some(Reverse((cost, state))) = queue.pop() {
+            if state.x == width - 1 && state.y == height - 1 && state.run >= crucible.min_run {
+                return Some(cost);
+            }
+            if cost > best[self.state_idx(&state, crucible)] {
+                continue;
+            }
+
+            for heading in Direction::ALL {
+                if heading == state.heading.opposite() {
+                    continue;
+                }
+
+                let run = if heading == state.heading {
+                    if state.run >= crucible.max_run { continue; }
+                    state.run + 1
+                } else {
+                    // a run of 0 is only the starting block, which can head off either way
+                    if state.run < crucible.min_run && state.run > 0 { continue; }
+                    1
+                };
+
+                let Some((x, y)) = self.step(state.x, state.y, heading) else { continue };
+                let next = State { x, y, heading, run };
+                let next_cost = cost + self.heat_loss[y][x];
+
+                let idx = self.state_idx(&next, crucible);
+                if next_cost < best[idx] {
+                    best[idx] = next_cost;
+                    queue.push(Reverse((next_cost, next)));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "102");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "94");
+}
+
+#[test]
+fn test_input_ultra_crucible() {
+    const EXAMPLE: &str = r"111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "71");
+}
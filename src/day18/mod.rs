@@ -0,0 +1,152 @@
+use crate::Part;
+
+use nom::{
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{self, one_of, space1},
+    error::VerboseError,
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let steps: Vec<DigStep> = input.filter_map(|line|  {
+            let (_, step) = dig_step(line.as_str()).ok()?;
+            Some(step)
+        }).collect();
+
+    let total = match part {
+        Part::Part1 => lagoon_volume(steps.iter().map(|s| (s.direction, s.distance))),
+        Part::Part2 => lagoon_volume(steps.iter().map(|s| s.decoded_colour())),
+    };
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn parse_from(value: char) -> Option<Self> {
+        match value {
+            'U' | '3' => Some(Self::Up),
+            'D' | '1' => Some(Self::Down),
+            'L' | '2' => Some(Self::Left),
+            'R' | '0' => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    fn delta(&self) -> (i64, i64) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct DigStep {
+    direction: Direction,
+    distance: i64,
+    colour: String,
+}
+
+impl DigStep {
+    /// the first five hex digits are the real distance, the last one is the direction.
+    fn decoded_colour(&self) -> (Direction, i64) {
+        let (distance, direction) = self.colour.split_at(5);
+        (
+            direction.chars().next().and_then(Direction::parse_from).expect("valid direction digit"),
+            i64::from_str_radix(distance, 16).expect("valid hex distance"),
+        )
+    }
+}
+
+/// shoelace formula gives the area enclosed by the trench's center line, and by
+/// Pick's theorem the trench itself adds half its length plus one on top of that.
+fn lagoon_volume(steps: impl Iterator<Item = (Direction, i64)>) -> i64 {
+    let (mut x, mut y) = (0i64, 0i64);
+    let mut twice_area = 0i64;
+    let mut boundary = 0i64;
+
+    for (direction, distance) in steps {
+        let (dx, dy) = direction.delta();
+        let (nx, ny) = (x + dx * distance, y + dy * distance);
+
+        twice_area += x * ny - nx * y;
+        boundary += distance;
+        (x, y) = (nx, ny);
+    }
+
+    twice_area.abs() / 2 + boundary / 2 + 1
+}
+
+// `R 6 (#70c710)`
+fn dig_step(input: &str) -> IResult<&str, DigStep, VerboseError<&str>> {
+    let (input, (direction, _, distance, _, colour)) = tuple((
+            one_of("UDLR"),
+            space1,
+            complete::i64,
+            space1,
+            delimited(
+                tag("("),
+                preceded(tag("#"), take_while_m_n(6, 6, |c: char| c.is_ascii_hexdigit())),
+                tag(")"),
+            ),
+        ))(input)?;
+
+    Ok((
+        input,
+        DigStep {
+            direction: Direction::parse_from(direction).expect("one_of only matches directions"),
+            distance,
+            colour: String::from(colour),
+        },
+    ))
+}
+
+
+#[test]
+fn test_decoded_colour() {
+    let (_, step) = dig_step("R 6 (#70c710)").expect("valid dig step");
+    assert_eq!(step.decoded_colour(), (Direction::Right, 461937));
+
+    let (_, step) = dig_step("U 2 (#7a21e3)").expect("valid dig step");
+    assert_eq!(step.decoded_colour(), (Direction::Up, 500254));
+}
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "62");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "952408144115");
+}
@@ -0,0 +1,299 @@
+use crate::Part;
+
+use std::collections::HashMap;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1, one_of},
+    combinator::map,
+    error::VerboseError,
+    sequence::{delimited, pair, preceded, terminated, tuple},
+    multi::separated_list1,
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let mut workflows: HashMap<String, Workflow> = HashMap::new();
+    let mut parts: Vec<Rating> = Vec::new();
+
+    input.for_each(|line| {
+        if let Ok((_, (name, flow))) = workflow(line.as_str()) {
+            workflows.insert(String::from(name), flow);
+        } else if let Ok((_, r)) = rating(line.as_str()) {
+            parts.push(r);
+        }
+    });
+
+    let system = System { workflows };
+
+    let total: u64 = match part {
+        Part::Part1 => {
+            parts.iter()
+                .filter(|r| system.accepts(r))
+                .map(|r| r.0.iter().sum::<u64>())
+                .sum()
+        }
+        Part::Part2 => {
+            system.accepted_combinations(RatingRange([(1, 4000); 4]), &Target::Workflow(String::from("in")))
+        }
+    };
+
+    format!("{}", total)
+}
+
+/// indexes into a rating's `[x, m, a, s]` values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Category {
+    X = 0,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    fn parse_from(value: char) -> Option<Self> {
+        match value {
+            'x' => Some(Self::X),
+            'm' => Some(Self::M),
+            'a' => Some(Self::A),
+            's' => Some(Self::S),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Rule {
+    LessThan(Category, u64, Target),
+    GreaterThan(Category, u64, Target),
+    Always(Target),
+}
+
+#[derive(Debug, PartialEq)]
+struct Workflow {
+    rules: Vec<Rule>,
+}
+
+/// a part's `[x, m, a, s]` ratings
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rating([u64; 4]);
+
+/// inclusive `(min, max)` bounds for each of `[x, m, a, s]`
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RatingRange([(u64, u64); 4]);
+
+impl RatingRange {
+    fn combinations(&self) -> u64 {
+        self.0.iter()
+            .map(|(lo, hi)| if hi >= lo { hi - lo + 1 } else { 0 })
+            .product()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().any(|(lo, hi)| lo > hi)
+    }
+
+    /// splits into the part that matches `rule` and the part that falls through to the next one.
+    fn split(&self, rule: &Rule) -> (Option<RatingRange>, Option<RatingRange>) {
+        let (category, matched_bounds, rest_bounds) = match rule {
+            Rule::LessThan(category, value, _) => {
+                let (lo, hi) = self.0[*category as usize];
+                (category, (lo, u64::min(hi, value.saturating_sub(1))), (u64::max(lo, *value), hi))
+            }
+            Rule::GreaterThan(category, value, _) => {
+                let (lo, hi) = self.0[*category as usize];
+                (category, (u64::max(lo, value + 1), hi), (lo, u64::min(hi, *value)))
+            }
+            Rule::Always(_) => return (Some(*self), None),
+        };
+
+        let mut matched = *self;
+        matched.0[*category as usize] = matched_bounds;
+        let mut rest = *self;
+        rest.0[*category as usize] = rest_bounds;
+
+        (
+            Some(matched).filter(|r| !r.is_empty()),
+            Some(rest).filter(|r| !r.is_empty()),
+        )
+    }
+}
+
+impl Rule {
+    fn target(&self) -> &Target {
+        match self {
+            Rule::LessThan(_, _, target) => target,
+            Rule::GreaterThan(_, _, target) => target,
+            Rule::Always(target) => target,
+        }
+    }
+
+    fn matches(&self, rating: &Rating) -> bool {
+        match self {
+            Rule::LessThan(category, value, _) => rating.0[*category as usize] < *value,
+            Rule::GreaterThan(category, value, _) => rating.0[*category as usize] > *value,
+            Rule::Always(_) => true,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct System {
+    workflows: HashMap<String, Workflow>,
+}
+
+impl System {
+    fn workflow(&self, name: &str) -> &Workflow {
+        self.workflows.get(name).expect("rules must only send parts to known workflows")
+    }
+
+    fn accepts(&self, rating: &Rating) -> bool {
+        let mut name = "in";
+        loop {
+            let rule = self.workflow(name).rules.iter()
+                .find(|r| r.matches(rating))
+                .expect("workflows must end with a fallback rule");
+
+            match rule.target() {
+                Target::Accept => return true,
+                Target::Reject => return false,
+                Target::Workflow(next) => name = next.as_str(),
+            }
+        }
+    }
+
+    /// pushes the whole `range` hyper-rectangle through `target`, splitting it on
+    /// each rule's condition, and counts every rating that ends up accepted.
+    fn accepted_combinations(&self, range: RatingRange, target: &Target) -> u64 {
+        let name = match target {
+            Target::Accept => return range.combinations(),
+            Target::Reject => return 0,
+            Target::Workflow(name) => name,
+        };
+
+        let mut total = 0;
+        let mut remaining = Some(range);
+        for rule in self.workflow(name).rules.iter() {
+            let Some(cur) = remaining else { break };
+
+            let (matched, rest) = cur.split(rule);
+            if let Some(matched) = matched {
+                total += self.accepted_combinations(matched, rule.target());
+            }
+            remaining = rest;
+        }
+
+        total
+    }
+}
+
+// `px{a<2006:qkq,m>2090:A,rfg}`
+fn workflow(input: &str) -> IResult<&str, (&str, Workflow), VerboseError<&str>> {
+    let (input, (name, rules)) = pair(
+            alpha1,
+            delimited(tag("{"), separated_list1(tag(","), rule), tag("}")),
+        )(input)?;
+
+    Ok((
+        input,
+        (name, Workflow { rules }),
+    ))
+}
+
+// `a<2006:qkq` or `rfg`
+fn rule(input: &str) -> IResult<&str, Rule, VerboseError<&str>> {
+    alt((
+        map(
+            tuple((one_of("xmas"), one_of("<>"), terminated(complete::u64, tag(":")), target)),
+            |(category, op, value, target)| {
+                let category = Category::parse_from(category).expect("one_of only matches categories");
+                match op {
+                    '<' => Rule::LessThan(category, value, target),
+                    _ => Rule::GreaterThan(category, value, target),
+                }
+            },
+        ),
+        map(target, Rule::Always),
+    ))(input)
+}
+
+fn target(input: &str) -> IResult<&str, Target, VerboseError<&str>> {
+    map(alpha1, |name| match name {
+        "A" => Target::Accept,
+        "R" => Target::Reject,
+        _ => Target::Workflow(String::from(name)),
+    })(input)
+}
+
+// `{x=787,m=2655,a=1222,s=2876}`
+fn rating(input: &str) -> IResult<&str, Rating, VerboseError<&str>> {
+    let (input, values) = delimited(
+            tag("{"),
+            separated_list1(tag(","), preceded(pair(one_of("xmas"), tag("=")), complete::u64)),
+            tag("}"),
+        )(input)?;
+
+    let values: [u64; 4] = values.try_into()
+        .map_err(|_| nom::Err::Error(nom::error::ParseError::from_error_kind(input, nom::error::ErrorKind::Count)))?;
+
+    Ok((
+        input,
+        Rating(values),
+    ))
+}
+
+
+#[test]
+fn test_parser() {
+    let (remaining, (name, parsed)) = workflow("px{a<2006:qkq,m>2090:A,rfg}").expect("valid workflow");
+    assert_eq!(remaining, "");
+    assert_eq!(name, "px");
+    assert_eq!(parsed.rules, vec![
+        Rule::LessThan(Category::A, 2006, Target::Workflow(String::from("qkq"))),
+        Rule::GreaterThan(Category::M, 2090, Target::Accept),
+        Rule::Always(Target::Workflow(String::from("rfg"))),
+    ]);
+
+    let (remaining, parsed) = rating("{x=787,m=2655,a=1222,s=2876}").expect("valid rating");
+    assert_eq!(remaining, "");
+    assert_eq!(parsed, Rating([787, 2655, 1222, 2876]));
+}
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "19114");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "167409079868000");
+}
@@ -0,0 +1,285 @@
+use crate::{math::lcm, Part};
+
+use std::collections::{HashMap, VecDeque};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, one_of, space0},
+    combinator::opt,
+    error::VerboseError,
+    sequence::{pair, separated_pair, tuple},
+    multi::separated_list1,
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let definitions: Vec<Definition> = input.filter_map(|line|  {
+            let (_, definition) = module(line.as_str()).ok()?;
+            Some(definition)
+        }).collect();
+
+    let mut network = Network::from(definitions);
+
+    let total = match part {
+        Part::Part1 => {
+            let (mut low, mut high) = (0u64, 0u64);
+            for _ in 0..1000 {
+                network.press_button(|pulse| match pulse.high {
+                    true => high += 1,
+                    false => low += 1,
+                });
+            }
+            low * high
+        }
+        Part::Part2 => network.presses_until_rx(),
+    };
+
+    format!("{}", total)
+}
+
+/// a module as written in the input, ie `%a -> inv, con`
+#[derive(Debug, PartialEq)]
+struct Definition {
+    prefix: Option<char>,
+    name: String,
+    outputs: Vec<String>,
+}
+
+#[derive(Debug)]
+enum ModuleKind {
+    Broadcaster,
+    /// `%`, currently on or off
+    FlipFlop(bool),
+    /// `&`, remembering the last pulse from each input
+    Conjunction(HashMap<String, bool>),
+    /// only ever receives pulses, like `rx` or `output`
+    Untyped,
+}
+
+#[derive(Debug)]
+struct Module {
+    kind: ModuleKind,
+    outputs: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Pulse {
+    from: String,
+    to: String,
+    high: bool,
+}
+
+/// well past the cycles in the puzzle input, which come from 12 bit counters.
+const MAX_PRESSES: u64 = 100_000;
+
+#[derive(Debug)]
+struct Network {
+    modules: HashMap<String, Module>,
+}
+
+impl Network {
+    fn from(definitions: Vec<Definition>) -> Network {
+        let mut modules: HashMap<String, Module> = definitions.into_iter()
+            .map(|Definition { prefix, name, outputs }| {
+                let kind = match prefix {
+                    Some('%') => ModuleKind::FlipFlop(false),
+                    Some(_) => ModuleKind::Conjunction(HashMap::new()),
+                    None if name == "broadcaster" => ModuleKind::Broadcaster,
+                    None => ModuleKind::Untyped,
+                };
+                (name, Module { kind, outputs })
+            })
+            .collect();
+
+        // conjunctions start off remembering a low pulse from every one of their inputs
+        let links: Vec<(String, String)> = modules.iter()
+            .flat_map(|(name, m)| m.outputs.iter().map(move |o| (name.clone(), o.clone())))
+            .collect();
+        for (from, to) in links {
+            let target = modules.entry(to)
+                .or_insert(Module { kind: ModuleKind::Untyped, outputs: Vec::new() });
+            if let ModuleKind::Conjunction(memory) = &mut target.kind {
+                memory.insert(from, false);
+            }
+        }
+
+        Network { modules }
+    }
+
+    /// sends a low pulse to the broadcaster and processes pulses in the order they
+    /// were sent until the network settles, reporting every pulse to `on_pulse`.
+    fn press_button(&mut self, mut on_pulse: impl FnMut(&Pulse)) {
+        let mut queue = VecDeque::from([Pulse {
+            from: String::from("button"),
+            to: String::from("broadcaster"),
+            high: false,
+        }]);
+
+        while let Some(pulse) = queue.pop_front() {
+            on_pulse(&pulse);
+
+            let Some(module) = self.modules.get_mut(&pulse.to) else { continue };
+            let sent = match &mut module.kind {
+                ModuleKind::Broadcaster => Some(pulse.high),
+                ModuleKind::FlipFlop(on) => {
+                    if pulse.high {
+                        None
+                    } else {
+                        *on = !*on;
+                        Some(*on)
+                    }
+                }
+                ModuleKind::Conjunction(memory) => {
+                    memory.insert(pulse.from.clone(), pulse.high);
+                    Some(!memory.values().all(|v| *v))
+                }
+                ModuleKind::Untyped => None,
+            };
+
+            if let Some(high) = sent {
+                queue.extend(module.outputs.iter().map(|o| Pulse {
+                    from: pulse.to.clone(),
+                    to: o.clone(),
+                    high,
+                }));
+            }
+        }
+    }
+
+    /// `rx` sits behind a single conjunction, which only sends it a low pulse once all of
+    /// its inputs last sent a high one.
+    ///
+    /// this relies on how the puzzle input is built: each of those inputs sends a high
+    /// pulse on its own fixed cycle of button presses and goes back to low within that
+    /// same press, so the only press where they line up is the LCM of their first highs.
+    /// networks without that shape can reach `rx` sooner or later than this reports.
+    ///
+    /// panics if any of those inputs hasn't sent a high pulse within `MAX_PRESSES`.
+    fn presses_until_rx(&mut self) -> u64 {
+        let feeder = self.modules.iter()
+            .find(|(_, m)| m.outputs.iter().any(|o| o == "rx"))
+            .map(|(name, _)| name.clone())
+            .expect("network must have a module feeding `rx`");
+
+        let mut cycles: HashMap<String, Option<u64>> = match &self.modules[&feeder].kind {
+            ModuleKind::Conjunction(memory) => memory.keys().map(|k| (k.clone(), None)).collect(),
+            _ => panic!("the module feeding `rx` must be a conjunction"),
+        };
+
+        let mut presses = 0u64;
+        while cycles.values().any(|c| c.is_none()) {
+            if presses == MAX_PRESSES {
+                let mut silent: Vec<&str> = cycles.iter()
+                    .filter(|(_, c)| c.is_none())
+                    .map(|(name, _)| name.as_str())
+                    .collect();
+                silent.sort();
+                panic!("{} never sent `{feeder}` a high pulse in {MAX_PRESSES} presses", silent.join(", "));
+            }
+            presses += 1;
+            self.press_button(|pulse| {
+                if pulse.high && pulse.to == feeder {
+                    cycles.entry(pulse.from.clone())
+                        .and_modify(|c| { c.get_or_insert(presses); });
+                }
+            });
+        }
+
+        cycles.values()
+            .map(|c| c.expect("every cycle was found"))
+            .fold(1u64, lcm)
+    }
+}
+
+// `%a -> inv, con` or `broadcaster -> a, b, c`
+fn module(input: &str) -> IResult<&str, Definition, VerboseError<&str>> {
+    let (input, ((prefix, name), outputs)) = separated_pair(
+            pair(opt(one_of("%&")), alpha1),
+            tuple((space0, tag("->"), space0)),
+            separated_list1(pair(tag(","), space0), alpha1),
+        )(input)?;
+
+    Ok((
+        input,
+        Definition {
+            prefix,
+            name: String::from(name),
+            outputs: outputs.into_iter().map(String::from).collect(),
+        },
+    ))
+}
+
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE_1: &str = r"broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    const EXAMPLE_2: &str = r"broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    let lines = EXAMPLE_1.split('\n')
+        .map(String::from);
+    let output = solve(Box::new(lines), Part::Part1);
+    assert_eq!(output.as_str(), "32000000");
+
+    let lines = EXAMPLE_2.split('\n')
+        .map(String::from);
+    let output = solve(Box::new(lines), Part::Part1);
+    assert_eq!(output.as_str(), "11687500");
+}
+
+#[test]
+fn test_presses_until_rx() {
+    // two counters, resetting themselves once they reach 3 and 5 presses.  `ia` and `ib`
+    // only send `con` a high pulse on those presses, and drop back to low straight after.
+    const EXAMPLE: &str = r"broadcaster -> ax, bx
+%ax -> ay, na
+%ay -> na
+&na -> ia, ax
+&ia -> con
+%bx -> by, nb
+%by -> bz
+%bz -> nb
+&nb -> ib, by, bx
+&ib -> con
+&con -> rx";
+
+    let network = || Network::from(EXAMPLE.split('\n')
+        .filter_map(|line| module(line).ok().map(|(_, definition)| definition))
+        .collect());
+
+    // press until `rx` actually sees a low pulse
+    let mut brute_force = network();
+    let mut presses = 0u64;
+    let mut reached = false;
+    while !reached {
+        assert!(presses < 1000, "`rx` never saw a low pulse");
+        presses += 1;
+        brute_force.press_button(|pulse| reached |= pulse.to == "rx" && !pulse.high);
+    }
+
+    assert_eq!(network().presses_until_rx(), presses);
+    assert_eq!(presses, 15);
+}
+
+#[test]
+#[should_panic(expected = "b never sent `con` a high pulse")]
+fn test_presses_until_rx_gives_up() {
+    // `b` never receives anything, so it never sends anything either
+    const EXAMPLE: &str = r"broadcaster -> a
+%a -> con
+&b -> con
+&con -> rx";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    solve(Box::new(lines), Part::Part2);
+}
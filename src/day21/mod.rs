@@ -0,0 +1,145 @@
+use crate::Part;
+
+use std::collections::{HashSet, VecDeque};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let garden = Garden::from(input);
+
+    let total = match part {
+        Part::Part1 => garden.reachable(64),
+        Part::Part2 => garden.reachable_tiled(26501365),
+    };
+
+    format!("{}", total)
+}
+
+#[derive(Debug)]
+struct Garden {
+    rocks: Vec<Vec<bool>>,
+    start: (i64, i64),
+}
+
+impl Garden {
+    fn from(input: Box<dyn Iterator<Item = String>>) -> Garden {
+        let mut start = None;
+        let rocks = input
+            .filter(|line| !line.is_empty())
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars().enumerate().map(|(x, c)| {
+                    if c == 'S' {
+                        start = Some((x as i64, y as i64));
+                    }
+                    c == '#'
+                }).collect()
+            }).collect();
+
+        Garden { rocks, start: start.expect("garden must have a starting `S` plot") }
+    }
+
+    fn size(&self) -> (i64, i64) {
+        (self.rocks.first().map(|r| r.len()).unwrap_or(0) as i64, self.rocks.len() as i64)
+    }
+
+    fn is_rock(&self, x: i64, y: i64, tiled: bool) -> bool {
+        let (w, h) = self.size();
+        if !tiled && (x < 0 || y < 0 || x >= w || y >= h) {
+            return true;
+        }
+        self.rocks[y.rem_euclid(h) as usize][x.rem_euclid(w) as usize]
+    }
+
+    /// plots reachable in exactly `steps` steps.
+    ///
+    /// an elf can always step back and forth, so any plot reachable in at most
+    /// `steps` steps with the same parity as `steps` counts too.
+    fn count_reachable(&self, steps: u64, tiled: bool) -> u64 {
+        let mut seen: HashSet<(i64, i64)> = HashSet::from([self.start]);
+        let mut queue = VecDeque::from([(self.start, 0u64)]);
+        let mut count = 0;
+
+        while let Some(((x, y), dist)) = queue.pop_front() {
+            if dist % 2 == steps % 2 {
+                count += 1;
+            }
+            if dist == steps {
+                continue;
+            }
+
+            for (nx, ny) in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                if !self.is_rock(nx, ny, tiled) && seen.insert((nx, ny)) {
+                    queue.push_back(((nx, ny), dist + 1));
+                }
+            }
+        }
+
+        count
+    }
+
+    fn reachable(&self, steps: u64) -> u64 {
+        self.count_reachable(steps, false)
+    }
+
+    /// plots reachable in exactly `steps` steps on the infinitely repeating garden.
+    ///
+    /// the real input has a clear row and column through `S`, so once past the first
+    /// tile the reachable area grows as a quadratic in the number of whole tiles walked.
+    /// sampling it at `steps % width` plus 0, 1 and 2 widths fixes that quadratic.
+    fn reachable_tiled(&self, steps: u64) -> u64 {
+        let width = self.size().0 as u64;
+        let remainder = steps % width;
+        let tiles = steps / width;
+
+        if tiles < 3 {
+            return self.count_reachable(steps, true);
+        }
+
+        let samples: Vec<i64> = (0..3)
+            .map(|n| self.count_reachable(remainder + n * width, true) as i64)
+            .collect();
+
+        extrapolate_quadratic(&samples, tiles as i64) as u64
+    }
+}
+
+/// evaluates the quadratic through `(0, s0)`, `(1, s1)` and `(2, s2)` at `n`.
+fn extrapolate_quadratic(samples: &[i64], n: i64) -> i64 {
+    let (s0, s1, s2) = (samples[0], samples[1], samples[2]);
+    let first_diff = s1 - s0;
+    let second_diff = s2 - 2 * s1 + s0;
+
+    s0 + first_diff * n + second_diff * n * (n - 1) / 2
+}
+
+
+#[cfg(test)]
+const EXAMPLE: &str = r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    let garden = Garden::from(Box::new(EXAMPLE.split('\n').map(String::from)));
+    assert_eq!(garden.reachable(6), 16);
+}
+
+#[test]
+fn test_reachable_tiled() {
+    let garden = Garden::from(Box::new(EXAMPLE.split('\n').map(String::from)));
+    assert_eq!(garden.count_reachable(6, true), 16);
+    assert_eq!(garden.count_reachable(10, true), 50);
+    assert_eq!(garden.count_reachable(50, true), 1594);
+    assert_eq!(garden.count_reachable(100, true), 6536);
+
+    assert_eq!(extrapolate_quadratic(&[1, 4, 9], 5), 36);
+}
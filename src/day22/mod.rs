@@ -0,0 +1,178 @@
+use crate::Part;
+
+use std::collections::{HashSet, VecDeque};
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    error::VerboseError,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let bricks: Vec<Brick> = input.filter_map(|line|  {
+            let (_, brick) = brick(line.as_str()).ok()?;
+            Some(brick)
+        }).collect();
+
+    let stack = Stack::settle(bricks);
+
+    let total = match part {
+        Part::Part1 => {
+            (0..stack.len())
+                .filter(|id| stack.chain_reaction(*id) == 0)
+                .count()
+        }
+        Part::Part2 => {
+            (0..stack.len())
+                .map(|id| stack.chain_reaction(id))
+                .sum()
+        }
+    };
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Coord {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Brick {
+    min: Coord,
+    max: Coord,
+}
+
+impl Brick {
+    fn from(a: Coord, b: Coord) -> Brick {
+        Brick {
+            min: Coord { x: a.x.min(b.x), y: a.y.min(b.y), z: a.z.min(b.z) },
+            max: Coord { x: a.x.max(b.x), y: a.y.max(b.y), z: a.z.max(b.z) },
+        }
+    }
+
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.min.x..=self.max.x)
+            .flat_map(move |x| (self.min.y..=self.max.y).map(move |y| (x, y)))
+    }
+}
+
+/// the settled bricks, as graphs of which bricks rest on which.
+#[derive(Debug)]
+struct Stack {
+    supports: Vec<Vec<usize>>,
+    supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// drops the bricks lowest first, tracking the top of the pile (and which
+    /// brick it belongs to) for every `(x, y)` column in a height map.
+    fn settle(mut bricks: Vec<Brick>) -> Stack {
+        bricks.sort_by_key(|b| b.min.z);
+
+        let width = bricks.iter().map(|b| b.max.x + 1).max().unwrap_or(0);
+        let depth = bricks.iter().map(|b| b.max.y + 1).max().unwrap_or(0);
+        let mut height_map: Vec<Vec<(usize, Option<usize>)>> = vec![vec![(0, None); depth]; width];
+
+        let mut supports = vec![Vec::new(); bricks.len()];
+        let mut supported_by = vec![Vec::new(); bricks.len()];
+
+        for (id, brick) in bricks.iter().enumerate() {
+            let rest_on = brick.footprint()
+                .map(|(x, y)| height_map[x][y].0)
+                .max()
+                .unwrap_or(0);
+
+            let mut below: Vec<usize> = brick.footprint()
+                .filter_map(|(x, y)| match height_map[x][y] {
+                    (z, Some(other)) if z == rest_on => Some(other),
+                    _ => None,
+                })
+                .collect();
+            below.sort_unstable();
+            below.dedup();
+
+            below.iter().for_each(|other| supports[*other].push(id));
+            supported_by[id] = below;
+
+            let top = rest_on + (brick.max.z - brick.min.z) + 1;
+            brick.footprint().for_each(|(x, y)| height_map[x][y] = (top, Some(id)));
+        }
+
+        Stack { supports, supported_by }
+    }
+
+    fn len(&self) -> usize {
+        self.supports.len()
+    }
+
+    /// how many other bricks would fall if brick `id` were disintegrated.
+    ///
+    /// a brick falls once every brick holding it up has fallen, so this walks up
+    /// through the supports graph from `id` and only queues bricks left with none.
+    fn chain_reaction(&self, id: usize) -> usize {
+        let mut fallen: HashSet<usize> = HashSet::from([id]);
+        let mut queue = VecDeque::from([id]);
+
+        while let Some(cur) = queue.pop_front() {
+            for above in self.supports[cur].iter() {
+                if fallen.contains(above) {
+                    continue;
+                }
+                if self.supported_by[*above].iter().all(|b| fallen.contains(b)) {
+                    fallen.insert(*above);
+                    queue.push_back(*above);
+                }
+            }
+        }
+
+        fallen.len() - 1
+    }
+}
+
+// `1,0,1~1,2,1`
+fn brick(input: &str) -> IResult<&str, Brick, VerboseError<&str>> {
+    let (input, (a, b)) = separated_pair(coord, tag("~"), coord)(input)?;
+
+    Ok((
+        input,
+        Brick::from(a, b),
+    ))
+}
+
+fn coord(input: &str) -> IResult<&str, Coord, VerboseError<&str>> {
+    let (input, (x, _, y, _, z)) = tuple((
+            complete::u32, tag(","), complete::u32, tag(","), complete::u32,
+        ))(input)?;
+
+    Ok((
+        input,
+        Coord { x: x as usize, y: y as usize, z: z as usize },
+    ))
+}
+
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "5");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "7");
+}
@@ -0,0 +1,238 @@
+use crate::Part;
+
+use std::collections::HashMap;
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let map = TrailMap::from(input);
+
+    let slippery = match part {
+        Part::Part1 => true,
+        Part::Part2 => false,
+    };
+
+    let total = map.junction_graph(slippery).longest_path();
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+impl Tile {
+    fn parse_from(value: char) -> Tile {
+        match value {
+            '#' => Tile::Forest,
+            '^' => Tile::Slope(Direction::North),
+            '>' => Tile::Slope(Direction::East),
+            'v' => Tile::Slope(Direction::South),
+            '<' => Tile::Slope(Direction::West),
+            _ => Tile::Path,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+}
+
+type Coord = (usize, usize);
+
+#[derive(Debug)]
+struct TrailMap {
+    tiles: Vec<Vec<Tile>>,
+    start: Coord,
+    end: Coord,
+}
+
+impl TrailMap {
+    fn from(input: Box<dyn Iterator<Item = String>>) -> TrailMap {
+        let tiles: Vec<Vec<Tile>> = input
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().map(Tile::parse_from).collect())
+            .collect();
+
+        let open_in = |row: &Vec<Tile>| row.iter().position(|t| *t != Tile::Forest).expect("row must have an opening");
+        let start = (open_in(&tiles[0]), 0);
+        let end = (open_in(&tiles[tiles.len() - 1]), tiles.len() - 1);
+
+        TrailMap { tiles, start, end }
+    }
+
+    fn tile(&self, (x, y): Coord) -> Tile {
+        self.tiles[y][x]
+    }
+
+    fn step(&self, (x, y): Coord, direction: Direction) -> Option<Coord> {
+        let next = match direction {
+            Direction::North => (x, y.checked_sub(1)?),
+            Direction::East => (x + 1, y),
+            Direction::South => (x, y + 1),
+            Direction::West => (x.checked_sub(1)?, y),
+        };
+        if next.1 >= self.tiles.len() || next.0 >= self.tiles[next.1].len() || self.tile(next) == Tile::Forest {
+            return None;
+        }
+        Some(next)
+    }
+
+    /// neighbouring coords that can be stepped onto, only heading downhill off a slope when `slippery`.
+    fn neighbours(&self, coord: Coord, slippery: bool) -> Vec<Coord> {
+        Direction::ALL.iter()
+            .filter(|d| match self.tile(coord) {
+                Tile::Slope(downhill) if slippery => downhill == **d,
+                _ => true,
+            })
+            .filter_map(|d| self.step(coord, *d))
+            .collect()
+    }
+
+    fn is_junction(&self, coord: Coord) -> bool {
+        coord == self.start || coord == self.end || self.neighbours(coord, false).len() > 2
+    }
+
+    /// collapses every corridor between two junctions into a single weighted edge.
+    fn junction_graph(&self, slippery: bool) -> JunctionGraph {
+        let junctions: Vec<Coord> = self.tiles.iter().enumerate()
+            .flat_map(|(y, row)| {
+                row.iter().enumerate()
+                    .filter(|(_, t)| **t != Tile::Forest)
+                    .map(move |(x, _)| (x, y))
+            })
+            .filter(|c| self.is_junction(*c))
+            .collect();
+        assert!(junctions.len() <= 64, "junctions must fit in a u64 bitmask");
+
+        let ids: HashMap<Coord, usize> = junctions.iter().enumerate()
+            .map(|(id, c)| (*c, id))
+            .collect();
+
+        let edges = junctions.iter()
+            .map(|from| {
+                self.neighbours(*from, slippery).into_iter()
+                    .filter_map(|first| {
+                        let (to, len) = self.follow_corridor(*from, first, slippery)?;
+                        Some((ids[&to], len))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        JunctionGraph { edges, start: ids[&self.start], end: ids[&self.end] }
+    }
+
+    /// walks from junction `from` through `first` until reaching the next junction,
+    /// returning it and the corridor length.  dead ends and slopes walked up come back `None`.
+    fn follow_corridor(&self, from: Coord, first: Coord, slippery: bool) -> Option<(Coord, usize)> {
+        let (mut prev, mut cur) = (from, first);
+        let mut len = 1;
+
+        while !self.is_junction(cur) {
+            let next = self.neighbours(cur, slippery).into_iter()
+                .find(|n| *n != prev)?;
+            (prev, cur) = (cur, next);
+            len += 1;
+        }
+
+        Some((cur, len))
+    }
+}
+
+#[derive(Debug)]
+struct JunctionGraph {
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
+}
+
+impl JunctionGraph {
+    fn longest_path(&self) -> usize {
+        // the last junction before the end must head straight for it, or it would
+        // cut itself off, so stop there instead of searching its other corridors.
+        let into_end: Vec<(usize, usize)> = (0..self.edges.len())
+            .filter_map(|id| {
+                self.edges[id].iter()
+                    .find(|(to, _)| *to == self.end)
+                    .map(|(_, len)| (id, *len))
+            })
+            .collect();
+        let last_hop = match into_end[..] {
+            [only] => Some(only),
+            _ => None,
+        };
+
+        let mut best = 0;
+        self.search(self.start, 1 << self.start, 0, last_hop, &mut best);
+        best
+    }
+
+    /// depth first search over every simple path, tracking visited junctions as bits in `visited`.
+    fn search(&self, cur: usize, visited: u64, dist: usize, last_hop: Option<(usize, usize)>, best: &mut usize) {
+        if cur == self.end {
+            *best = usize::max(*best, dist);
+            return;
+        }
+        if let Some((before_end, len)) = last_hop {
+            if cur == before_end {
+                *best = usize::max(*best, dist + len);
+                return;
+            }
+        }
+
+        for (next, len) in self.edges[cur].iter() {
+            if visited & (1 << next) == 0 {
+                self.search(*next, visited | (1 << next), dist + len, last_hop, best);
+            }
+        }
+    }
+}
+
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "94");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "154");
+}
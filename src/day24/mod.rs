@@ -0,0 +1,243 @@
+use crate::Part;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, space0},
+    error::VerboseError,
+    sequence::{delimited, separated_pair, tuple},
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    let hail: Vec<Hailstone> = input.filter_map(|line|  {
+            let (_, stone) = hailstone(line.as_str()).ok()?;
+            Some(stone)
+        }).collect();
+
+    let total = match part {
+        Part::Part1 => {
+            future_crossings_within(&hail, 200_000_000_000_000, 400_000_000_000_000) as i128
+        }
+        Part::Part2 => {
+            let rock = rock_throw(&hail).expect("must be a rock that hits every hailstone");
+            rock.pos.iter().sum()
+        }
+    };
+
+    format!("{}", total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hailstone {
+    pos: [i128; 3],
+    vel: [i128; 3],
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn sub(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// whether `num / den` lies within `lo..=hi`, without doing the division.
+fn ratio_within(num: i128, den: i128, lo: i128, hi: i128) -> bool {
+    let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+    num >= lo * den && num <= hi * den
+}
+
+/// whether the XY paths of `a` and `b` cross inside the test area, ahead of both hailstones.
+///
+/// with `d = b.pos - a.pos`, the paths meet at `a.pos + a.vel * t` where
+/// `t = (d x b.vel) / (a.vel x b.vel)`, and at `b`'s `s = (d x a.vel) / (a.vel x b.vel)`.
+/// everything is kept as integer numerators over that shared denominator.
+fn crosses_within(a: &Hailstone, b: &Hailstone, lo: i128, hi: i128) -> bool {
+    let cross_2d = |u: [i128; 3], v: [i128; 3]| u[0] * v[1] - u[1] * v[0];
+
+    let den = cross_2d(a.vel, b.vel);
+    if den == 0 {
+        return false; // parallel
+    }
+
+    let d = sub(b.pos, a.pos);
+    let t = cross_2d(d, b.vel);
+    let s = cross_2d(d, a.vel);
+    if t.signum() * den.signum() < 0 || s.signum() * den.signum() < 0 {
+        return false; // crossed in the past
+    }
+
+    ratio_within(a.pos[0] * den + a.vel[0] * t, den, lo, hi)
+        && ratio_within(a.pos[1] * den + a.vel[1] * t, den, lo, hi)
+}
+
+fn future_crossings_within(hail: &[Hailstone], lo: i128, hi: i128) -> usize {
+    hail.iter().enumerate()
+        .map(|(idx, a)| {
+            hail[idx + 1..].iter()
+                .filter(|b| crosses_within(a, b, lo, hi))
+                .count()
+        })
+        .sum()
+}
+
+/// finds the rock that hits every hailstone.
+///
+/// a rock at `P` with velocity `V` hits hailstone `i` exactly when
+/// `(P - p_i) x (V - v_i) = 0`.  the `P x V` term is shared by every hailstone,
+/// so subtracting the equations for two hailstones leaves three linear equations,
+/// and two pairs give the six needed to solve for `P` and `V`.
+fn rock_throw(hail: &[Hailstone]) -> Option<Hailstone> {
+    let first = hail.first()?;
+    (1..hail.len())
+        .flat_map(|j| (j + 1..hail.len()).map(move |k| (j, k)))
+        .filter_map(|(j, k)| {
+            let mut rows = pair_equations(first, &hail[j]);
+            rows.extend(pair_equations(first, &hail[k]));
+
+            let solution = solve_exact(rows)?;
+            Some(Hailstone {
+                pos: [solution[0], solution[1], solution[2]],
+                vel: [solution[3], solution[4], solution[5]],
+            })
+        })
+        .find(|rock| {
+            hail.iter().all(|h| cross(sub(rock.pos, h.pos), sub(rock.vel, h.vel)) == [0, 0, 0])
+        })
+}
+
+/// `P x (v_j - v_i) + (p_j - p_i) x V = p_j x v_j - p_i x v_i`, as rows over
+/// the unknowns `[Px, Py, Pz, Vx, Vy, Vz]` with the right hand side last.
+fn pair_equations(i: &Hailstone, j: &Hailstone) -> Vec<[i128; 7]> {
+    let w = sub(j.vel, i.vel);
+    let u = sub(j.pos, i.pos);
+    let r = sub(cross(j.pos, j.vel), cross(i.pos, i.vel));
+
+    vec![
+        [0, w[2], -w[1], 0, -u[2], u[1], r[0]],
+        [-w[2], 0, w[0], u[2], 0, -u[0], r[1]],
+        [w[1], -w[0], 0, -u[1], u[0], 0, r[2]],
+    ]
+}
+
+/// the mersenne prime `2^61 - 1`.  any product of two residues fits in a `u128`.
+const MODULUS: u128 = (1 << 61) - 1;
+
+fn mod_pow(mut base: u128, mut exp: u128) -> u128 {
+    let mut result = 1;
+    base %= MODULUS;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % MODULUS;
+        }
+        base = base * base % MODULUS;
+        exp >>= 1;
+    }
+    result
+}
+
+/// solves the augmented system exactly with gaussian elimination modulo `MODULUS`.
+///
+/// plain elimination over integers or fractions overflows an `i128` on the real
+/// input.  since the true solution is integral and far smaller than `MODULUS / 2`,
+/// the residues map straight back to it; `None` if the system is singular.
+fn solve_exact(rows: Vec<[i128; 7]>) -> Option<[i128; 6]> {
+    let mut m: Vec<[u128; 7]> = rows.iter()
+        .map(|row| row.map(|v| v.rem_euclid(MODULUS as i128) as u128))
+        .collect();
+
+    for col in 0..6 {
+        let pivot = (col..m.len()).find(|r| m[*r][col] != 0)?;
+        m.swap(col, pivot);
+
+        let inv = mod_pow(m[col][col], MODULUS - 2);
+        m[col] = m[col].map(|v| v * inv % MODULUS);
+
+        for r in 0..m.len() {
+            if r == col || m[r][col] == 0 {
+                continue;
+            }
+            let factor = m[r][col];
+            let pivot_row = m[col];
+            for (value, pivot_value) in m[r].iter_mut().zip(pivot_row.iter()) {
+                *value = (*value + MODULUS - factor * pivot_value % MODULUS) % MODULUS;
+            }
+        }
+    }
+
+    let mut solution = [0i128; 6];
+    for (idx, value) in solution.iter_mut().enumerate() {
+        let residue = m[idx][6];
+        *value = if residue > MODULUS / 2 {
+            residue as i128 - MODULUS as i128
+        } else {
+            residue as i128
+        };
+    }
+    Some(solution)
+}
+
+// `19, 13, 30 @ -2,  1, -2`
+fn hailstone(input: &str) -> IResult<&str, Hailstone, VerboseError<&str>> {
+    let (input, (pos, vel)) = separated_pair(
+            triple,
+            delimited(space0, tag("@"), space0),
+            triple,
+        )(input)?;
+
+    Ok((
+        input,
+        Hailstone { pos, vel },
+    ))
+}
+
+fn triple(input: &str) -> IResult<&str, [i128; 3], VerboseError<&str>> {
+    let separator = || tuple((tag(","), space0));
+    let (input, (x, _, y, _, z)) = tuple((
+            complete::i64, separator(), complete::i64, separator(), complete::i64,
+        ))(input)?;
+
+    Ok((
+        input,
+        [x as i128, y as i128, z as i128],
+    ))
+}
+
+
+#[cfg(test)]
+const EXAMPLE: &str = r"19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+#[test]
+fn test_future_crossings() {
+    let hail: Vec<Hailstone> = EXAMPLE.split('\n')
+        .map(|l| hailstone(l).expect("valid hailstone").1)
+        .collect();
+
+    assert_eq!(future_crossings_within(&hail, 7, 27), 2);
+}
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+
+    let hail: Vec<Hailstone> = lines.clone()
+        .map(|l| hailstone(l.as_str()).expect("valid hailstone").1)
+        .collect();
+    let rock = rock_throw(&hail).expect("example has a rock throw");
+    assert_eq!(rock, Hailstone { pos: [24, 13, 10], vel: [-3, 1, 2] });
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "47");
+}
+
@@ -0,0 +1,162 @@
+use crate::Part;
+
+use std::collections::{HashMap, VecDeque};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, space0, space1},
+    error::VerboseError,
+    sequence::{separated_pair, tuple},
+    multi::separated_list1,
+    IResult,
+};
+
+pub(crate) fn solve(input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
+
+    if let Part::Part2 = part {
+        return String::from("day 25 has no part 2");
+    }
+
+    let mut graph = Graph::default();
+    input.for_each(|line| {
+        if let Ok((_, (name, others))) = connections(line.as_str()) {
+            others.iter().for_each(|other| graph.connect(name, other));
+        }
+    });
+
+    let group = graph.cut_group(3).expect("must be a 3 wire cut splitting the components");
+    let total = group * (graph.len() - group);
+
+    format!("{}", total)
+}
+
+/// component names are interned into ids, with each wire stored in both directions.
+#[derive(Debug, Default)]
+struct Graph {
+    ids: HashMap<String, usize>,
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    fn intern(&mut self, name: &str) -> usize {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+
+        let id = self.neighbours.len();
+        self.ids.insert(String::from(name), id);
+        self.neighbours.push(Vec::new());
+        id
+    }
+
+    fn connect(&mut self, a: &str, b: &str) {
+        let (a, b) = (self.intern(a), self.intern(b));
+        self.neighbours[a].push(b);
+        self.neighbours[b].push(a);
+    }
+
+    fn len(&self) -> usize {
+        self.neighbours.len()
+    }
+
+    /// size of the group containing component `0` once exactly `wires` wires are cut.
+    ///
+    /// every wire holds one unit of flow, so the max flow from component `0` to any
+    /// component on the far side of the cut is exactly `wires`.  once such a sink is
+    /// found, everything still reachable in the residual graph is on the near side.
+    fn cut_group(&self, wires: usize) -> Option<usize> {
+        (1..self.len()).find_map(|sink| {
+            let mut flow: HashMap<(usize, usize), i32> = HashMap::new();
+
+            for _ in 0..wires {
+                self.augment(0, sink, &mut flow)?;
+            }
+
+            match self.augment(0, sink, &mut flow) {
+                Some(()) => None,
+                None => Some(self.reachable_from(0, &flow).iter().filter(|r| **r).count()),
+            }
+        })
+    }
+
+    fn residual(&self, from: usize, to: usize, flow: &HashMap<(usize, usize), i32>) -> i32 {
+        1 - flow.get(&(from, to)).copied().unwrap_or(0)
+    }
+
+    /// marks every component reachable from `source` through wires with spare capacity.
+    fn reachable_from(&self, source: usize, flow: &HashMap<(usize, usize), i32>) -> Vec<bool> {
+        self.search(source, flow).into_iter()
+            .map(|prev| prev.is_some())
+            .collect()
+    }
+
+    /// breadth first search over the residual graph, returning each component's predecessor.
+    fn search(&self, source: usize, flow: &HashMap<(usize, usize), i32>) -> Vec<Option<usize>> {
+        let mut prev = vec![None; self.len()];
+        prev[source] = Some(source);
+        let mut queue = VecDeque::from([source]);
+
+        while let Some(cur) = queue.pop_front() {
+            for next in self.neighbours[cur].iter() {
+                if prev[*next].is_none() && self.residual(cur, *next, flow) > 0 {
+                    prev[*next] = Some(cur);
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        prev
+    }
+
+    /// pushes one more unit of flow from `source` to `sink`, if there's a path with room for it.
+    fn augment(&self, source: usize, sink: usize, flow: &mut HashMap<(usize, usize), i32>) -> Option<()> {
+        let prev = self.search(source, flow);
+        prev[sink]?;
+
+        let mut cur = sink;
+        while cur != source {
+            let from = prev[cur].expect("path was found");
+            *flow.entry((from, cur)).or_insert(0) += 1;
+            *flow.entry((cur, from)).or_insert(0) -= 1;
+            cur = from;
+        }
+
+        Some(())
+    }
+}
+
+// `jqt: rhn xhk nvd`
+fn connections(input: &str) -> IResult<&str, (&str, Vec<&str>), VerboseError<&str>> {
+    separated_pair(
+        alpha1,
+        tuple((tag(":"), space0)),
+        separated_list1(space1, alpha1),
+    )(input)
+}
+
+
+#[test]
+// sanity check vs example input
+fn test_input() {
+    const EXAMPLE: &str = r"jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    let lines = EXAMPLE.split('\n')
+        .map(String::from);
+    
+    let output = solve(Box::new(lines.clone()), Part::Part1);
+    assert_eq!(output.as_str(), "54");
+
+    let output = solve(Box::new(lines), Part::Part2);
+    assert_eq!(output.as_str(), "day 25 has no part 2");
+}
@@ -4,32 +4,36 @@ mod day01;
 mod day02;
 mod day03;
 mod day04;
-// mod day05;
-// mod day06;
-// mod day07;
-// mod day08;
-// mod day09;
-// mod day10;
-// mod day11;
-// mod day12;
-// mod day13;
-// mod day14;
-// mod day15;
-// mod day16;
-// mod day17;
-// mod day18;
-// mod day19;
-// mod day20;
-// mod day21;
-// mod day22;
-// mod day23;
-// mod day24;
-// mod day25;
-
-use std::fmt::Debug;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+mod math;
+
+use std::fmt::{Debug, Display};
 
 use clap::ValueEnum;
 
+pub use day01::{CalibrationError, DigitVocabulary, ExplainFormat, MalformedLines, VocabularyError};
+
 /// the main trait each 'day' module should implement to solve that day's input
 pub trait Solve {
     fn solve(&self, input: Box<dyn Iterator<Item = String>>, part: Part) -> String;
@@ -64,36 +68,110 @@ pub enum Day {
     Day25,
 }
 
+/// extra settings for the days that support them.  everything defaults to the puzzle's own rules.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// spelled out digits for day 1's part 2
+    pub vocabulary: DigitVocabulary,
+    /// how day 1 handles lines without any digits
+    pub malformed_lines: MalformedLines,
+    /// report how each line was read instead of the answer (day 1 only)
+    pub explain: Option<ExplainFormat>,
+}
+
+/// a day's answer, along with anything worth mentioning about how it was reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub answer: String,
+    pub warnings: Vec<String>,
+}
+
+impl From<String> for Solution {
+    fn from(answer: String) -> Self {
+        Solution { answer, warnings: Vec::new() }
+    }
+}
+
+#[derive(Debug)]
+pub enum SolveError {
+    Calibration(CalibrationError),
+    /// an explain report was asked for, but only day 1 has one
+    ExplainUnsupported,
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Calibration(err) => write!(f, "{err}"),
+            SolveError::ExplainUnsupported => write!(f, "explain reports are only available for day 1"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<CalibrationError> for SolveError {
+    fn from(err: CalibrationError) -> Self {
+        SolveError::Calibration(err)
+    }
+}
+
 impl Solve for Day {
+    /// solves with the default `Options`, panicking if the input can't be solved.
     fn solve(&self, input: Box<dyn Iterator<Item = String>>, part: Part) -> String {
-        match self {
-            Day::Day01 => day01::solve(input, part),
+        match self.solve_with(input, part, &Options::default()) {
+            Ok(solution) => solution.answer,
+            Err(err) => panic!("{err}"),
+        }
+    }
+}
+
+impl Day {
+    pub fn solve_with(&self, input: Box<dyn Iterator<Item = String>>, part: Part, options: &Options) -> Result<Solution, SolveError> {
+        if let (Day::Day01, Some(format)) = (self, options.explain) {
+            return Ok(Solution::from(day01::explain(input, part, &options.vocabulary, format)));
+        }
+        if options.explain.is_some() {
+            return Err(SolveError::ExplainUnsupported);
+        }
+
+        let answer = match self {
+            Day::Day01 => {
+                let calibration = day01::solve(input, part, &options.vocabulary, options.malformed_lines)?;
+                let warnings = match calibration.skipped {
+                    0 => Vec::new(),
+                    n => vec![format!("skipped {n} line(s) without any digits")],
+                };
+                return Ok(Solution { answer: calibration.total.to_string(), warnings });
+            }
             Day::Day02 => day02::solve(input, part),
             Day::Day03 => day03::solve(input, part),
             Day::Day04 => day04::solve(input, part),
-            Day::Day05 => unimplemented!(), // day05::solve(input, part),
-            Day::Day06 => unimplemented!(), // day06::solve(input, part),
-            Day::Day07 => unimplemented!(), // day07::solve(input, part),
-            Day::Day08 => unimplemented!(), // day08::solve(input, part),
-            Day::Day09 => unimplemented!(), // day09::solve(input, part),
-            Day::Day10 => unimplemented!(), // day10::solve(input, part),
-            Day::Day11 => unimplemented!(), // day11::solve(input, part),
-            Day::Day12 => unimplemented!(), // day12::solve(input, part),
-            Day::Day13 => unimplemented!(), // day13::solve(input, part),
-            Day::Day14 => unimplemented!(), // day14::solve(input, part),
-            Day::Day15 => unimplemented!(), // day15::solve(input, part),
-            Day::Day16 => unimplemented!(), // day16::solve(input, part),
-            Day::Day17 => unimplemented!(), // day17::solve(input, part),
-            Day::Day18 => unimplemented!(), // day18::solve(input, part),
-            Day::Day19 => unimplemented!(), // day19::solve(input, part),
-            Day::Day20 => unimplemented!(), // day20::solve(input, part),
-            Day::Day21 => unimplemented!(), // day21::solve(input, part),
-            Day::Day22 => unimplemented!(), // day22::solve(input, part),
-            Day::Day23 => unimplemented!(), // day23::solve(input, part),
-            Day::Day24 => unimplemented!(), // day24::solve(input, part),
-            Day::Day25 => unimplemented!(), // day25::solve(input, part),
-        }
-    }    
+            Day::Day05 => day05::solve(input, part),
+            Day::Day06 => day06::solve(input, part),
+            Day::Day07 => day07::solve(input, part),
+            Day::Day08 => day08::solve(input, part),
+            Day::Day09 => day09::solve(input, part),
+            Day::Day10 => day10::solve(input, part),
+            Day::Day11 => day11::solve(input, part),
+            Day::Day12 => day12::solve(input, part),
+            Day::Day13 => day13::solve(input, part),
+            Day::Day14 => day14::solve(input, part),
+            Day::Day15 => day15::solve(input, part),
+            Day::Day16 => day16::solve(input, part),
+            Day::Day17 => day17::solve(input, part),
+            Day::Day18 => day18::solve(input, part),
+            Day::Day19 => day19::solve(input, part),
+            Day::Day20 => day20::solve(input, part),
+            Day::Day21 => day21::solve(input, part),
+            Day::Day22 => day22::solve(input, part),
+            Day::Day23 => day23::solve(input, part),
+            Day::Day24 => day24::solve(input, part),
+            Day::Day25 => day25::solve(input, part),
+        };
+
+        Ok(Solution::from(answer))
+    }
 }
 
 
@@ -146,7 +224,7 @@ mod bench {
 
         let benches = vec![
             // (day, fn)
-            (1, day01::solve as fn(Box<(dyn Iterator<Item = String> + 'static)>, Part) -> String),
+            (1, (|input, part| Day::Day01.solve(input, part)) as fn(Box<(dyn Iterator<Item = String> + 'static)>, Part) -> String),
             (2, day02::solve),
             // (3, day03::solve),
             // (4, day04::solve),
@@ -6,10 +6,11 @@ use std::{
         BufRead,
         stdin, stdout, Write
     }, 
-    fs::File
+    fs::File,
+    process::exit,
 };
 
-use advent::{Day, Part, Solve};
+use advent::{Day, DigitVocabulary, ExplainFormat, MalformedLines, Options, Part};
 
 
 #[derive(Parser)]
@@ -23,6 +24,20 @@ struct Cli {
     /// optional path to specify the input file to use.
     #[arg(short,long)]
     input: Option<PathBuf>,
+
+    /// day 1 only: spelled out digits to look for in part 2.
+    /// either a built-in (english, german, french, spanish) or a path to a `word digit` table.
+    #[arg(long, value_parser = parse_vocabulary)]
+    vocabulary: Option<DigitVocabulary>,
+
+    /// day 1 only: what to do with lines that have no digits in them.
+    #[arg(long, value_enum, default_value_t = MalformedLines::Fail)]
+    malformed_lines: MalformedLines,
+
+    /// day 1 only: print how each line was read (first/last digit, offsets, numeral or word)
+    /// instead of the answer.
+    #[arg(long, value_enum)]
+    explain: Option<ExplainFormat>,
 }
 
 
@@ -33,11 +48,33 @@ fn main() {
         get_input_file(args.input)
         .unwrap_or(get_stdinput());
 
-    let answer = args.day.solve(buf, args.part);
+    let options = Options {
+        vocabulary: args.vocabulary.unwrap_or_default(),
+        malformed_lines: args.malformed_lines,
+        explain: args.explain,
+    };
 
-    let _ = stdout().write_all(format!("{}\n", answer).as_bytes());
+    let solution = match args.day.solve_with(buf, args.part, &options) {
+        Ok(solution) => solution,
+        Err(err) => {
+            eprintln!("error: {err}");
+            exit(1);
+        }
+    };
+
+    solution.warnings.iter().for_each(|w| eprintln!("warning: {w}"));
+    let _ = stdout().write_all(format!("{}\n", solution.answer).as_bytes());
 }   
 
+fn parse_vocabulary(arg: &str) -> Result<DigitVocabulary, String> {
+    match DigitVocabulary::builtin(arg) {
+        Some(vocabulary) => Ok(vocabulary),
+        None => DigitVocabulary::load(arg).map_err(|e| e.to_string()),
+    }
+}
+
+// unreadable lines are skipped rather than ending the input
+#[allow(clippy::lines_filter_map_ok)]
 fn get_input_file(path: Option<PathBuf>) -> Option<Box<dyn Iterator<Item = String>>> {
 
     let path = path?;
@@ -50,6 +87,7 @@ fn get_input_file(path: Option<PathBuf>) -> Option<Box<dyn Iterator<Item = Strin
     )
 }
 
+#[allow(clippy::lines_filter_map_ok)]
 fn get_stdinput() -> Box<dyn Iterator<Item = String>> {
     Box::new(
         stdin().lines().filter_map(|l| l.ok() )
@@ -0,0 +1,16 @@
+pub(crate) fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// divides before multiplying to keep the intermediate value as small as possible.
+pub(crate) fn lcm(a: u64, b: u64) -> u64 {
+    (a / gcd(a, b)).checked_mul(b).expect("lcm must fit in a u64")
+}
+
+
+#[test]
+fn test_lcm() {
+    assert_eq!(lcm(4, 6), 12);
+    assert_eq!(lcm(1, 7), 7);
+    assert_eq!(lcm(20_000_000_000, 30_000_000_000), 60_000_000_000);
+}